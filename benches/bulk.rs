@@ -0,0 +1,73 @@
+//! Benchmarks comparing the bulk conversion APIs of `Unaligned` against naive element-by-element loops.
+//!
+//! Run with `cargo bench --features alloc`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use unaligned::Unaligned;
+
+const LEN: usize = 1 << 16;
+const ITERATIONS: u32 = 200;
+
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    // warm up caches and the allocator before measuring
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed() / ITERATIONS;
+    println!("{name:<40} {:>10.1} µs/iter", elapsed.as_secs_f64() * 1e6);
+}
+
+fn main() {
+    let aligned: Vec<u64> = (0..LEN as u64).collect();
+    let unaligned: Vec<Unaligned<u64>> = aligned.iter().copied().map(Unaligned::new).collect();
+    let mut aligned_dst = vec![0u64; LEN];
+    let mut unaligned_dst = vec![Unaligned::new(0u64); LEN];
+
+    bench("copy_from_aligned (naive loop)", || {
+        for (dst, src) in unaligned_dst.iter_mut().zip(black_box(&aligned)) {
+            dst.set(*src);
+        }
+        black_box(&unaligned_dst);
+    });
+    bench("copy_from_aligned (bulk)", || {
+        Unaligned::copy_from_aligned(&mut unaligned_dst, black_box(&aligned));
+        black_box(&unaligned_dst);
+    });
+
+    bench("copy_into_aligned (naive loop)", || {
+        for (dst, src) in aligned_dst.iter_mut().zip(black_box(&unaligned)) {
+            *dst = src.get();
+        }
+        black_box(&aligned_dst);
+    });
+    bench("copy_into_aligned (bulk)", || {
+        Unaligned::copy_into_aligned(black_box(&unaligned), &mut aligned_dst);
+        black_box(&aligned_dst);
+    });
+
+    bench("vec_from_aligned (naive loop)", || {
+        let vec = black_box(aligned.clone());
+        black_box(vec.into_iter().map(Unaligned::new).collect::<Vec<_>>());
+    });
+    bench("vec_from_aligned (bulk)", || {
+        let vec = black_box(aligned.clone());
+        black_box(Unaligned::vec_from_aligned(vec));
+    });
+
+    bench("vec_into_aligned (naive loop)", || {
+        let vec = black_box(unaligned.clone());
+        black_box(vec.into_iter().map(Unaligned::into_inner).collect::<Vec<_>>());
+    });
+    bench("vec_into_aligned (bulk)", || {
+        let vec = black_box(unaligned.clone());
+        black_box(Unaligned::vec_into_aligned(vec));
+    });
+}
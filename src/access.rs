@@ -0,0 +1,193 @@
+//! A common interface over the different kinds of unaligned storage in this crate.
+
+use crate::{
+    cell::UnalignedCell,
+    endian::ByteOrder,
+    int::{I24, I40, I48, I56, U24, U40, U48, U56},
+    Unaligned,
+};
+
+/// Access to an unaligned value of type `T`, independent of how it is stored.
+///
+/// This trait is implemented for [`Unaligned<T>`] and [`UnalignedCell<T>`], for shared references to
+/// [`UnalignedCell<T>`], for mutable references to any implementor, and for the odd-width integers in
+/// [`int`](crate::int). Every method takes `self` by mutable reference, so that storage requiring exclusive access
+/// can implement it. Shared access is available by implementing it for a shared reference, as `&UnalignedCell<T>`
+/// does.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{access::UnalignedAccess, cell::UnalignedCell, int::U24Le, Unaligned};
+/// fn bump<A: UnalignedAccess<u32>>(mut value: A) -> u32 {
+///     value.modify(|v| *v += 1);
+///     value.load()
+/// }
+///
+/// let mut unaligned = Unaligned::new(1);
+/// let cell = UnalignedCell::new(2);
+/// let mut int = U24Le::new(3).unwrap();
+/// assert_eq!(2, bump(&mut unaligned));
+/// assert_eq!(3, bump(&cell));
+/// assert_eq!(4, bump(&mut int));
+/// ```
+pub trait UnalignedAccess<T> {
+    /// Copy the value.
+    fn load(&mut self) -> T
+    where
+        T: Copy;
+
+    /// Set the value. The previous value is dropped.
+    fn store(&mut self, value: T);
+
+    /// Set the value, and return the previous value.
+    fn replace(&mut self, value: T) -> T;
+
+    /// Mutably borrow the value and perform some computation with it.
+    fn modify<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
+impl<T> UnalignedAccess<T> for Unaligned<T> {
+    fn load(&mut self) -> T
+    where
+        T: Copy,
+    {
+        self.get()
+    }
+
+    fn store(&mut self, value: T) {
+        self.set(value);
+    }
+
+    fn replace(&mut self, value: T) -> T {
+        Unaligned::replace(self, value)
+    }
+
+    fn modify<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.with_mut(f)
+    }
+}
+
+/// Because the cell is accessed by mutable reference, no runtime borrow checks are needed.
+impl<T> UnalignedAccess<T> for UnalignedCell<T> {
+    fn load(&mut self) -> T
+    where
+        T: Copy,
+    {
+        self.get_mut().get()
+    }
+
+    fn store(&mut self, value: T) {
+        self.get_mut().set(value);
+    }
+
+    fn replace(&mut self, value: T) -> T {
+        self.get_mut().replace(value)
+    }
+
+    fn modify<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.get_mut().with_mut(f)
+    }
+}
+
+/// ## Panics
+/// Each method panics if the contents of the cell are already borrowed.
+impl<T> UnalignedAccess<T> for &UnalignedCell<T> {
+    fn load(&mut self) -> T
+    where
+        T: Copy,
+    {
+        *self.borrow()
+    }
+
+    fn store(&mut self, value: T) {
+        *self.borrow() = value;
+    }
+
+    fn replace(&mut self, value: T) -> T {
+        UnalignedCell::replace(self, value)
+    }
+
+    fn modify<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut self.borrow())
+    }
+}
+
+impl<T, A: UnalignedAccess<T> + ?Sized> UnalignedAccess<T> for &mut A {
+    fn load(&mut self) -> T
+    where
+        T: Copy,
+    {
+        (**self).load()
+    }
+
+    fn store(&mut self, value: T) {
+        (**self).store(value);
+    }
+
+    fn replace(&mut self, value: T) -> T {
+        (**self).replace(value)
+    }
+
+    fn modify<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        (**self).modify(f)
+    }
+}
+
+macro_rules! impl_access_odd_int {
+    ($($ty:ident($native:ty)),* $(,)?) => {
+        $(
+            /// ## Panics
+            /// Storing a value, or modifying the value to one, that is out of range for the integer type panics.
+            impl<E: ByteOrder> UnalignedAccess<$native> for $ty<E> {
+                fn load(&mut self) -> $native {
+                    self.get()
+                }
+
+                fn store(&mut self, value: $native) {
+                    self.set(value);
+                }
+
+                fn replace(&mut self, value: $native) -> $native {
+                    let previous = self.get();
+                    self.set(value);
+                    previous
+                }
+
+                fn modify<R, F>(&mut self, f: F) -> R
+                where
+                    F: FnOnce(&mut $native) -> R,
+                {
+                    let mut value = self.get();
+                    let result = f(&mut value);
+                    self.set(value);
+                    result
+                }
+            }
+        )*
+    };
+}
+
+impl_access_odd_int!(
+    U24(u32),
+    I24(i32),
+    U40(u64),
+    I40(i64),
+    U48(u64),
+    I48(i64),
+    U56(u64),
+    I56(i64),
+);
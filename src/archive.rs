@@ -0,0 +1,617 @@
+//! A zero-copy serialization format whose archived types are made entirely of unaligned fields.
+//!
+//! A value is serialized with [`to_bytes`], which writes any out-of-line data (such as the contents of strings and
+//! vectors) first and the archived root value last. Out-of-line data is referenced by [relative pointers](crate::rel),
+//! so an archive remains valid wherever it is placed in memory. Because every archived type has
+//! an alignment of 1, an archive can be accessed in place from any `&[u8]` with [`access`], which validates the
+//! archive once, without copying or realigning it. Values are stored in native byte order.
+//!
+//! ## Example
+//! ```
+//! # use unaligned::archive;
+//! let value = vec![Some(String::from("alpha")), None, Some(String::from("gamma"))];
+//! let bytes = archive::to_bytes(&value);
+//!
+//! // the archive may start at any offset
+//! let mut buf = vec![0u8; 3];
+//! buf.extend_from_slice(&bytes);
+//! let archived = archive::access::<Vec<Option<String>>>(&buf[3..]).unwrap();
+//! assert_eq!(3, archived.len());
+//! assert_eq!("gamma", archived[2].as_option().unwrap().as_str());
+//! assert!(archived[1].is_none());
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Debug, Display},
+    mem::{self, offset_of, MaybeUninit},
+    ops::Deref,
+    ptr::addr_of_mut,
+    str,
+};
+
+use crate::{
+    pod::Pod,
+    rel::{RelPtr, RelSlice},
+    Unaligned,
+};
+
+/// The error returned when an archive fails validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveError {
+    pos: usize,
+}
+
+impl ArchiveError {
+    /// Construct a new error for the archived value at the given position.
+    pub const fn new(pos: usize) -> Self {
+        Self { pos }
+    }
+
+    /// Get the position in the archive of the value that failed validation.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArchiveError {}
+
+impl Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid archived value at byte {}", self.pos)
+    }
+}
+
+/// A type that can be accessed in place inside an archive.
+///
+/// ## Safety
+/// Implementors must ensure that the type has the following properties.
+/// - The type has an alignment of 1, and contains no padding bytes.
+/// - The type contains no interior mutability.
+/// - [`Portable::verify`] returns `Ok` only if the bytes at the given position form a valid value of the type, and
+///   all out-of-line data it references lies within the given bytes and is valid.
+pub unsafe trait Portable {
+    /// Check that the bytes at position `pos` form a valid value of this type. The caller ensures that
+    /// `pos + size_of::<Self>()` does not exceed the length of `bytes`.
+    fn verify(bytes: &[u8], pos: usize) -> Result<(), ArchiveError>;
+}
+
+/// A type that can be serialized into an archive.
+///
+/// Serialization happens in two steps. First, [`Archive::serialize`] writes any out-of-line data that the value
+/// needs, and returns a resolver describing where it was written. Then, [`Archive::resolve`] writes the archived
+/// value itself, using the resolver to compute relative offsets.
+///
+/// ## Example
+/// ```
+/// # use core::{mem::offset_of, ptr::addr_of_mut};
+/// # use unaligned::{archive::{self, Archive, ArchiveError, ArchivedString, Portable, Serializer}, Unaligned};
+/// struct Entry {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// #[repr(C)]
+/// struct ArchivedEntry {
+///     id: Unaligned<u32>,
+///     name: ArchivedString,
+/// }
+///
+/// // SAFETY: every field is portable and has alignment 1, and every field is verified
+/// unsafe impl Portable for ArchivedEntry {
+///     fn verify(bytes: &[u8], pos: usize) -> Result<(), ArchiveError> {
+///         Unaligned::<u32>::verify(bytes, pos + offset_of!(ArchivedEntry, id))?;
+///         ArchivedString::verify(bytes, pos + offset_of!(ArchivedEntry, name))
+///     }
+/// }
+///
+/// impl Archive for Entry {
+///     type Archived = ArchivedEntry;
+///     type Resolver = ((), usize);
+///
+///     fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver {
+///         (self.id.serialize(serializer), self.name.serialize(serializer))
+///     }
+///
+///     unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut ArchivedEntry) {
+///         // SAFETY: each field is resolved at its own position within the archived entry
+///         unsafe {
+///             let id = pos + offset_of!(ArchivedEntry, id);
+///             self.id.resolve(id, resolver.0, addr_of_mut!((*out).id));
+///             let name = pos + offset_of!(ArchivedEntry, name);
+///             self.name.resolve(name, resolver.1, addr_of_mut!((*out).name));
+///         }
+///     }
+/// }
+///
+/// let bytes = archive::to_bytes(&Entry { id: 7, name: String::from("seven") });
+/// let entry = archive::access::<Entry>(&bytes).unwrap();
+/// assert_eq!(7, entry.id.get());
+/// assert_eq!("seven", entry.name.as_str());
+/// ```
+pub trait Archive {
+    /// The archived form of this type.
+    type Archived: Portable;
+
+    /// Information about the out-of-line data written by [`Archive::serialize`].
+    type Resolver;
+
+    /// Write any out-of-line data needed by this value, and return a resolver describing it.
+    fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver;
+
+    /// Write the archived form of this value to `out`, which is at position `pos` in the archive.
+    ///
+    /// ## Safety
+    /// The caller must ensure that `out` is valid for writes, points to zeroed memory at position `pos` in the
+    /// archive, and that the resolver was returned by [`Archive::serialize`] on this value for the same archive.
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived);
+}
+
+/// A serializer that writes an archive into a byte buffer.
+#[derive(Debug, Default)]
+pub struct Serializer {
+    bytes: Vec<u8>,
+}
+
+impl Serializer {
+    /// Construct a new serializer with an empty buffer.
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Get the position at which the next value will be written.
+    pub fn pos(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Write the given bytes to the archive, and return the position at which they were written.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> usize {
+        let pos = self.pos();
+        self.bytes.extend_from_slice(bytes);
+        pos
+    }
+
+    /// Serialize and resolve the given value, and return the position of its archived form.
+    pub fn archive<T: Archive + ?Sized>(&mut self, value: &T) -> usize {
+        let resolver = value.serialize(self);
+        let pos = self.reserve::<T::Archived>(1);
+        // SAFETY: The reserved bytes are zeroed and valid for writes, and Portable types have alignment 1.
+        unsafe { value.resolve(pos, resolver, self.bytes.as_mut_ptr().add(pos).cast()) };
+        pos
+    }
+
+    /// Serialize and resolve the given values as a contiguous array, and return the position of the first archived
+    /// value.
+    pub fn archive_slice<T: Archive>(&mut self, values: &[T]) -> usize {
+        let resolvers: Vec<_> = values.iter().map(|value| value.serialize(self)).collect();
+        let pos = self.reserve::<T::Archived>(values.len());
+        for (i, (value, resolver)) in values.iter().zip(resolvers).enumerate() {
+            let value_pos = pos + i * mem::size_of::<T::Archived>();
+            // SAFETY: The reserved bytes are zeroed and valid for writes, and Portable types have alignment 1.
+            unsafe {
+                value.resolve(
+                    value_pos,
+                    resolver,
+                    self.bytes.as_mut_ptr().add(value_pos).cast(),
+                )
+            };
+        }
+        pos
+    }
+
+    /// Consume this serializer and return the archive.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Reserve zeroed space for `len` values of type `T`, and return its position.
+    fn reserve<T: Portable>(&mut self, len: usize) -> usize {
+        const {
+            assert!(
+                mem::align_of::<T>() == 1,
+                "portable types should have alignment 1"
+            )
+        };
+        let pos = self.pos();
+        self.bytes.resize(pos + mem::size_of::<T>() * len, 0);
+        pos
+    }
+}
+
+/// Serialize the given value into a new archive.
+pub fn to_bytes<T: Archive + ?Sized>(value: &T) -> Vec<u8> {
+    let mut serializer = Serializer::new();
+    serializer.archive(value);
+    serializer.into_bytes()
+}
+
+/// Validate the given archive, and return a reference to its archived root value. If the archive is invalid, this
+/// function returns an error.
+///
+/// ## Example
+/// ```
+/// # use unaligned::archive;
+/// // an archive too short to hold its root value
+/// assert!(archive::access::<u64>(&[0; 7]).is_err());
+///
+/// // a string whose contents lie before the start of the archive
+/// let bytes = archive::to_bytes("hello");
+/// assert!(archive::access::<String>(&bytes).is_ok());
+/// assert!(archive::access::<String>(&bytes[1..]).is_err());
+///
+/// // a string whose contents are not valid UTF-8
+/// let mut bytes = archive::to_bytes("hello");
+/// bytes[0] = 0xff;
+/// assert!(archive::access::<String>(&bytes).is_err());
+///
+/// // a bool that is neither 0 nor 1
+/// assert!(archive::access::<bool>(&[2]).is_err());
+///
+/// // an option whose tag is neither 0 nor 1
+/// let mut bytes = archive::to_bytes(&Some(1u32));
+/// let tag = bytes.len() - 5;
+/// bytes[tag] = 2;
+/// assert!(archive::access::<Option<u32>>(&bytes).is_err());
+///
+/// // a string with a null relative pointer but a nonzero length
+/// let mut bytes = [0u8; 8];
+/// bytes[4..].copy_from_slice(&4u32.to_ne_bytes());
+/// assert!(archive::access::<String>(&bytes).is_err());
+/// ```
+pub fn access<T: Archive + ?Sized>(bytes: &[u8]) -> Result<&T::Archived, ArchiveError> {
+    let pos = bytes
+        .len()
+        .checked_sub(mem::size_of::<T::Archived>())
+        .ok_or(ArchiveError::new(0))?;
+    T::Archived::verify(bytes, pos)?;
+    // SAFETY: The archive was just validated.
+    Ok(unsafe { access_unchecked::<T>(bytes) })
+}
+
+/// Get a reference to the archived root value of the given archive, without validating it.
+///
+/// ## Safety
+/// The archive must be valid, for example because it was produced by [`to_bytes`] or previously passed to
+/// [`access`].
+pub unsafe fn access_unchecked<T: Archive + ?Sized>(bytes: &[u8]) -> &T::Archived {
+    const {
+        assert!(
+            mem::align_of::<T::Archived>() == 1,
+            "portable types should have alignment 1"
+        )
+    };
+    let pos = bytes.len() - mem::size_of::<T::Archived>();
+    // SAFETY: The caller has ensured that the archive is valid, so the root value is valid and in bounds. Portable
+    // types have alignment 1.
+    unsafe { &*bytes.as_ptr().add(pos).cast() }
+}
+
+/// Read a plain value at the given position in an archive.
+fn read_at<T: Pod>(bytes: &[u8], pos: usize) -> Result<T, ArchiveError> {
+    bytes
+        .get(pos..)
+        .and_then(|bytes| bytes.get(..mem::size_of::<T>()))
+        .and_then(Unaligned::<T>::from_bytes)
+        .map(Unaligned::get)
+        .ok_or(ArchiveError::new(pos))
+}
+
+/// Compute the offset from position `from` to position `to` in an archive.
+///
+/// ## Panics
+/// This function panics if the offset does not fit in an `i32`.
+fn relative_offset(from: usize, to: usize) -> i32 {
+    let offset = (to as isize).wrapping_sub(from as isize);
+    i32::try_from(offset).expect("archive offsets should fit in an i32")
+}
+
+/// Check that the target of the relative slice at position `pos` lies within the archive, and return its position
+/// and length. A nonempty slice must not have a null offset.
+fn verify_rel_slice<T>(bytes: &[u8], pos: usize) -> Result<(usize, usize), ArchiveError> {
+    let len = read_at::<u32>(bytes, pos + mem::size_of::<RelPtr<T>>())? as usize;
+    let byte_len = len
+        .checked_mul(mem::size_of::<T>())
+        .ok_or(ArchiveError::new(pos))?;
+    let offset = read_at::<i32>(bytes, pos)?;
+    if offset == 0 && len != 0 {
+        return Err(ArchiveError::new(pos));
+    }
+    let start = pos
+        .checked_add_signed(offset as isize)
+        .filter(|&start| {
+            start
+                .checked_add(byte_len)
+                .is_some_and(|end| end <= bytes.len())
+        })
+        .ok_or(ArchiveError::new(pos))?;
+    Ok((start, len))
+}
+
+/// Convert a length to its archived form.
+///
+/// ## Panics
+/// This function panics if the length does not fit in a `u32`.
+fn archived_len(len: usize) -> u32 {
+    u32::try_from(len).expect("archived lengths should fit in a u32")
+}
+
+// primitive types
+
+// SAFETY: Unaligned<T> has alignment 1, and Pod types have no padding, no interior mutability, and no invalid bytes.
+unsafe impl<T: Pod> Portable for Unaligned<T> {
+    fn verify(_bytes: &[u8], _pos: usize) -> Result<(), ArchiveError> {
+        Ok(())
+    }
+}
+
+// SAFETY: bool has alignment 1 and no padding, and the only valid bytes are checked.
+unsafe impl Portable for bool {
+    fn verify(bytes: &[u8], pos: usize) -> Result<(), ArchiveError> {
+        match read_at::<u8>(bytes, pos)? {
+            0 | 1 => Ok(()),
+            _ => Err(ArchiveError::new(pos)),
+        }
+    }
+}
+
+macro_rules! impl_archive_pod {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Archive for $ty {
+                type Archived = Unaligned<$ty>;
+                type Resolver = ();
+
+                fn serialize(&self, _serializer: &mut Serializer) -> Self::Resolver {}
+
+                unsafe fn resolve(&self, _pos: usize, _resolver: Self::Resolver, out: *mut Self::Archived) {
+                    // SAFETY: The caller has ensured that out is valid for writes.
+                    unsafe { out.write(Unaligned::new(*self)) };
+                }
+            }
+        )*
+    };
+}
+
+impl_archive_pod!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Archive for bool {
+    type Archived = bool;
+    type Resolver = ();
+
+    fn serialize(&self, _serializer: &mut Serializer) -> Self::Resolver {}
+
+    unsafe fn resolve(&self, _pos: usize, _resolver: Self::Resolver, out: *mut Self::Archived) {
+        // SAFETY: The caller has ensured that out is valid for writes.
+        unsafe { out.write(*self) };
+    }
+}
+
+// strings
+
+/// The archived form of [`String`] and [`str`](prim@str).
+#[repr(C)]
+pub struct ArchivedString {
+    bytes: RelSlice<u8>,
+}
+
+impl ArchivedString {
+    /// Get the archived string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The archive was validated, so the relative slice points to valid UTF-8 within the archive.
+        unsafe { str::from_utf8_unchecked(Unaligned::slice_as_bytes(self.bytes.get())) }
+    }
+}
+
+// SAFETY: ArchivedString is made of a relative slice, whose target is checked to be in bounds and valid UTF-8.
+unsafe impl Portable for ArchivedString {
+    fn verify(bytes: &[u8], pos: usize) -> Result<(), ArchiveError> {
+        let (start, len) = verify_rel_slice::<u8>(bytes, pos + offset_of!(Self, bytes))?;
+        str::from_utf8(&bytes[start..start + len]).map_err(|_| ArchiveError::new(pos))?;
+        Ok(())
+    }
+}
+
+impl Deref for ArchivedString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl Debug for ArchivedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for ArchivedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for ArchivedString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ArchivedString {}
+
+impl PartialEq<str> for ArchivedString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl Archive for str {
+    type Archived = ArchivedString;
+    type Resolver = usize;
+
+    fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver {
+        serializer.write_bytes(self.as_bytes())
+    }
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        let offset = relative_offset(pos + offset_of!(ArchivedString, bytes), resolver);
+        let archived = ArchivedString {
+            bytes: RelSlice::from_raw_parts(offset, archived_len(self.len())),
+        };
+        // SAFETY: The caller has ensured that out is valid for writes.
+        unsafe { out.write(archived) };
+    }
+}
+
+impl Archive for String {
+    type Archived = ArchivedString;
+    type Resolver = usize;
+
+    fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver {
+        self.as_str().serialize(serializer)
+    }
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        // SAFETY: The caller upholds the safety requirements of resolve.
+        unsafe { self.as_str().resolve(pos, resolver, out) };
+    }
+}
+
+// vectors
+
+/// The archived form of [`Vec<T>`] and `[T]`, containing values of the archived type `T`.
+#[repr(C)]
+pub struct ArchivedVec<T> {
+    values: RelSlice<T>,
+}
+
+impl<T: Portable> ArchivedVec<T> {
+    /// Get the archived values.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The archive was validated, so the relative slice points to valid values within the archive.
+        // Portable types have alignment 1, so Unaligned<T> has the same layout as T.
+        unsafe { &*(self.values.get() as *const [Unaligned<T>] as *const [T]) }
+    }
+}
+
+// SAFETY: ArchivedVec is made of a relative slice, whose target is checked to be in bounds, and each value is
+// verified.
+unsafe impl<T: Portable> Portable for ArchivedVec<T> {
+    fn verify(bytes: &[u8], pos: usize) -> Result<(), ArchiveError> {
+        let (start, len) = verify_rel_slice::<T>(bytes, pos + offset_of!(Self, values))?;
+        (0..len).try_for_each(|i| T::verify(bytes, start + i * mem::size_of::<T>()))
+    }
+}
+
+impl<T: Portable> Deref for ArchivedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: Portable + Debug> Debug for ArchivedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: Archive> Archive for [T] {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = usize;
+
+    fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver {
+        serializer.archive_slice(self)
+    }
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        let offset = relative_offset(pos + offset_of!(ArchivedVec<T::Archived>, values), resolver);
+        let archived = ArchivedVec {
+            values: RelSlice::from_raw_parts(offset, archived_len(self.len())),
+        };
+        // SAFETY: The caller has ensured that out is valid for writes.
+        unsafe { out.write(archived) };
+    }
+}
+
+impl<T: Archive> Archive for Vec<T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = usize;
+
+    fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver {
+        self.as_slice().serialize(serializer)
+    }
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        // SAFETY: The caller upholds the safety requirements of resolve.
+        unsafe { self.as_slice().resolve(pos, resolver, out) };
+    }
+}
+
+// options
+
+/// The archived form of [`Option<T>`], containing a value of the archived type `T`.
+#[repr(C)]
+pub struct ArchivedOption<T> {
+    tag: u8,
+    value: MaybeUninit<T>,
+}
+
+impl<T> ArchivedOption<T> {
+    /// Get the archived value, if there is one.
+    pub fn as_option(&self) -> Option<&T> {
+        // SAFETY: The archive was validated, so the value is initialized if the tag is set.
+        (self.tag != 0).then(|| unsafe { self.value.assume_init_ref() })
+    }
+
+    /// Return `true` if there is an archived value.
+    pub fn is_some(&self) -> bool {
+        self.tag != 0
+    }
+
+    /// Return `true` if there is no archived value.
+    pub fn is_none(&self) -> bool {
+        self.tag == 0
+    }
+}
+
+// SAFETY: ArchivedOption is made of a byte and a type with alignment 1, so it has no padding. The tag is checked,
+// and the value is verified if it is present.
+unsafe impl<T: Portable> Portable for ArchivedOption<T> {
+    fn verify(bytes: &[u8], pos: usize) -> Result<(), ArchiveError> {
+        match read_at::<u8>(bytes, pos + offset_of!(Self, tag))? {
+            0 => Ok(()),
+            1 => T::verify(bytes, pos + offset_of!(Self, value)),
+            _ => Err(ArchiveError::new(pos)),
+        }
+    }
+}
+
+impl<T: Debug> Debug for ArchivedOption<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.as_option(), f)
+    }
+}
+
+impl<T: Archive> Archive for Option<T> {
+    type Archived = ArchivedOption<T::Archived>;
+    type Resolver = Option<T::Resolver>;
+
+    fn serialize(&self, serializer: &mut Serializer) -> Self::Resolver {
+        self.as_ref().map(|value| value.serialize(serializer))
+    }
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        if let (Some(value), Some(resolver)) = (self, resolver) {
+            let value_pos = pos + offset_of!(ArchivedOption<T::Archived>, value);
+            // SAFETY: The caller has ensured that out is valid for writes, and the value is resolved at its own
+            // position. If there is no value, the memory is left zeroed.
+            unsafe {
+                addr_of_mut!((*out).tag).write(1);
+                value.resolve(value_pos, resolver, addr_of_mut!((*out).value).cast());
+            }
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! A bump arena that packs values of any type without padding between them.
+
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, Layout},
+    vec::Vec,
+};
+use core::{
+    cell::{Cell, RefCell},
+    fmt::{self, Debug},
+    iter,
+    marker::PhantomData,
+    mem,
+    ptr::NonNull,
+    slice,
+};
+
+use crate::Unaligned;
+
+/// The capacity of the first chunk allocated by an arena, unless a larger allocation is requested.
+const MIN_CHUNK_CAPACITY: usize = 4096;
+
+/// An arena that allocates values of any type back to back, with no padding between them.
+///
+/// Because [`Unaligned<T>`] has an alignment of 1, the arena never needs to pad an allocation. Memory is obtained in
+/// chunks, each of which is filled before the next is allocated. Values that need to be dropped are dropped when the
+/// arena is reset or dropped, in the order they were allocated.
+///
+/// Values in the arena may borrow data that outlives the arena, for the lifetime `'a`.
+///
+/// ## Example
+/// ```
+/// # use unaligned::arena::UnalignedArena;
+/// let arena = UnalignedArena::new();
+/// let flag = arena.alloc(true);
+/// let id = arena.alloc(42u64);
+/// let name = arena.alloc(String::from("record"));
+/// id.with_mut(|id| *id += 1);
+/// name.with_mut(|name| name.push('s'));
+/// assert_eq!(43, id.get());
+/// assert_eq!(1 + 8 + core::mem::size_of::<String>(), arena.stats().allocated_bytes());
+/// ```
+///
+/// Values are dropped in the order they were allocated.
+/// ```
+/// # use unaligned::arena::UnalignedArena;
+/// # use std::cell::RefCell;
+/// struct Noisy<'a>(&'a RefCell<Vec<u32>>, u32);
+///
+/// impl Drop for Noisy<'_> {
+///     fn drop(&mut self) {
+///         self.0.borrow_mut().push(self.1);
+///     }
+/// }
+///
+/// let log = RefCell::new(Vec::new());
+/// let mut arena = UnalignedArena::new();
+/// arena.alloc(Noisy(&log, 1));
+/// arena.alloc(Noisy(&log, 2));
+/// arena.reset();
+/// assert_eq!(vec![1, 2], *log.borrow());
+/// arena.alloc(Noisy(&log, 3));
+/// arena.alloc(Noisy(&log, 4));
+/// drop(arena);
+/// assert_eq!(vec![1, 2, 3, 4], *log.borrow());
+/// ```
+///
+/// Values cannot borrow data that is dropped before the arena.
+/// ```compile_fail
+/// # use unaligned::arena::UnalignedArena;
+/// struct Borrower<'a>(&'a Vec<u32>);
+///
+/// impl Drop for Borrower<'_> {
+///     fn drop(&mut self) {
+///         println!("{:?}", self.0);
+///     }
+/// }
+///
+/// let arena = UnalignedArena::new();
+/// {
+///     let values = vec![1, 2, 3];
+///     arena.alloc(Borrower(&values));
+/// }
+/// drop(arena);
+/// ```
+pub struct UnalignedArena<'a> {
+    chunks: RefCell<Vec<Chunk>>,
+    cursor: Cell<usize>,
+    drops: RefCell<Vec<DropEntry>>,
+    allocations: Cell<usize>,
+    allocated_bytes: Cell<usize>,
+    // invariant, so that a shared borrow of the arena cannot shorten the lifetime of the values it drops
+    marker: PhantomData<fn(&'a ()) -> &'a ()>,
+}
+
+impl<'a> UnalignedArena<'a> {
+    /// Construct a new, empty arena. No memory is allocated until the first value is allocated.
+    pub const fn new() -> Self {
+        Self {
+            chunks: RefCell::new(Vec::new()),
+            cursor: Cell::new(0),
+            drops: RefCell::new(Vec::new()),
+            allocations: Cell::new(0),
+            allocated_bytes: Cell::new(0),
+            marker: PhantomData,
+        }
+    }
+
+    /// Construct a new arena whose first chunk has capacity for at least the given number of bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let arena = Self::new();
+        if capacity != 0 {
+            arena.chunks.borrow_mut().push(Chunk::new(capacity));
+        }
+        arena
+    }
+
+    /// Move the given value into the arena, and return a mutable reference to it.
+    pub fn alloc<T: 'a>(&self, value: T) -> &mut Unaligned<T> {
+        &mut self.alloc_iter(iter::once(value))[0]
+    }
+
+    /// Compute a value with the given function and move it into the arena, returning a mutable reference to it.
+    /// The function may itself allocate from the arena.
+    ///
+    /// This is a convenience wrapper around [`alloc`](UnalignedArena::alloc): the value is computed before any arena
+    /// memory is reserved, and then moved into the arena, so it is not guaranteed to be constructed in place.
+    pub fn alloc_with<T: 'a, F>(&self, f: F) -> &mut Unaligned<T>
+    where
+        F: FnOnce() -> T,
+    {
+        self.alloc(f())
+    }
+
+    /// Copy the given slice into the arena, and return a mutable reference to the copy.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::arena::UnalignedArena;
+    /// let arena = UnalignedArena::new();
+    /// arena.alloc(0u8);
+    /// let values = arena.alloc_slice_copy(&[1u32, 2, 3]);
+    /// values[1].set(5);
+    /// assert_eq!(5, values[1].get());
+    /// assert_eq!(13, arena.stats().allocated_bytes());
+    /// ```
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [Unaligned<T>] {
+        self.alloc_iter(src.iter().copied())
+    }
+
+    /// Drop every value in the arena and make its memory available for reuse. Only the most recently allocated chunk
+    /// is kept, and the rest are freed.
+    pub fn reset(&mut self) {
+        self.run_drops();
+        let chunks = self.chunks.get_mut();
+        if chunks.len() > 1 {
+            chunks.drain(..chunks.len() - 1);
+        }
+        self.cursor.set(0);
+        self.allocations.set(0);
+        self.allocated_bytes.set(0);
+    }
+
+    /// Get statistics about the allocations made from this arena.
+    pub fn stats(&self) -> ArenaStats {
+        let chunks = self.chunks.borrow();
+        ArenaStats {
+            allocations: self.allocations.get(),
+            allocated_bytes: self.allocated_bytes.get(),
+            capacity_bytes: chunks.iter().map(|chunk| chunk.capacity).sum(),
+            chunks: chunks.len(),
+        }
+    }
+
+    /// Move the values produced by the given iterator into a single allocation in the arena, and return a mutable
+    /// reference to them. At most as many values as the iterator reports are taken from it.
+    ///
+    /// The values are dropped with the arena, so values that need to be dropped must outlive `'a`. The public methods
+    /// ensure this with a `T: 'a` bound, or with a `T: Copy` bound, since `Copy` values never need to be dropped.
+    // each allocation is disjoint, so handing out mutable references from a shared borrow is sound
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_iter<T, I>(&self, values: I) -> &mut [Unaligned<T>]
+    where
+        I: ExactSizeIterator<Item = T>,
+    {
+        let len = values.len();
+        let size = mem::size_of::<T>()
+            .checked_mul(len)
+            .expect("allocation size should not overflow");
+        let ptr = self.reserve(size).cast::<Unaligned<T>>();
+        let mut initialized = 0;
+        for value in values.take(len) {
+            // SAFETY: The index is less than len, so the slot is within the reserved memory.
+            let slot = unsafe { ptr.as_ptr().add(initialized) };
+            // SAFETY: The reserved memory is valid for writes of the value, and Unaligned<T> has alignment 1.
+            unsafe { slot.write(Unaligned::new(value)) };
+            if mem::needs_drop::<T>() {
+                self.drops.borrow_mut().push(DropEntry {
+                    // SAFETY: The slot is derived from a non-null pointer.
+                    ptr: unsafe { NonNull::new_unchecked(slot.cast()) },
+                    drop: drop_unaligned::<T>,
+                });
+            }
+            initialized += 1;
+        }
+        // SAFETY: The first `initialized` values were just initialized, and the reserved memory is never handed out
+        // again until the arena is reset, which requires a mutable borrow of the arena.
+        unsafe { slice::from_raw_parts_mut(ptr.as_ptr(), initialized) }
+    }
+
+    /// Reserve the given number of bytes, allocating a new chunk if the current one is too small. The statistics of
+    /// the arena are only updated once the memory has been reserved.
+    fn reserve(&self, len: usize) -> NonNull<u8> {
+        let ptr = if len == 0 {
+            NonNull::dangling()
+        } else {
+            let mut chunks = self.chunks.borrow_mut();
+            let cursor = self.cursor.get();
+            match chunks.last() {
+                Some(chunk) if chunk.capacity - cursor >= len => {
+                    self.cursor.set(cursor + len);
+                    // SAFETY: The cursor is within the chunk, and the chunk has room for len more bytes.
+                    unsafe { NonNull::new_unchecked(chunk.ptr.as_ptr().add(cursor)) }
+                }
+                last => {
+                    let capacity = last
+                        .map_or(MIN_CHUNK_CAPACITY, |chunk| chunk.capacity.saturating_mul(2))
+                        .max(len);
+                    let chunk = Chunk::new(capacity);
+                    let ptr = chunk.ptr;
+                    chunks.push(chunk);
+                    self.cursor.set(len);
+                    ptr
+                }
+            }
+        };
+        self.allocations.set(self.allocations.get() + 1);
+        self.allocated_bytes.set(self.allocated_bytes.get() + len);
+        ptr
+    }
+
+    fn run_drops(&mut self) {
+        // the list is taken first, so that a panicking destructor leaks values instead of dropping them twice
+        for entry in mem::take(self.drops.get_mut()) {
+            // SAFETY: Each entry points to a live value of the type its drop function was instantiated with, and
+            // the value is never used again.
+            unsafe { (entry.drop)(entry.ptr.as_ptr()) };
+        }
+    }
+}
+
+// trait implementations
+
+impl Drop for UnalignedArena<'_> {
+    fn drop(&mut self) {
+        self.run_drops();
+    }
+}
+
+impl Default for UnalignedArena<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for UnalignedArena<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnalignedArena")
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Statistics about the allocations made from an [`UnalignedArena`] since it was created or last reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaStats {
+    allocations: usize,
+    allocated_bytes: usize,
+    capacity_bytes: usize,
+    chunks: usize,
+}
+
+impl ArenaStats {
+    /// Get the number of allocations made.
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+
+    /// Get the total number of bytes allocated. Because allocations are not padded, this is the sum of the sizes of
+    /// every allocated value.
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated_bytes
+    }
+
+    /// Get the total capacity in bytes of the chunks owned by the arena.
+    pub fn capacity_bytes(&self) -> usize {
+        self.capacity_bytes
+    }
+
+    /// Get the number of chunks owned by the arena.
+    pub fn chunks(&self) -> usize {
+        self.chunks
+    }
+}
+
+/// A block of memory owned by an arena, allocated with an alignment of 1.
+struct Chunk {
+    ptr: NonNull<u8>,
+    capacity: usize,
+}
+
+impl Chunk {
+    fn new(capacity: usize) -> Self {
+        let layout = Self::layout(capacity);
+        // SAFETY: The capacity is never zero, so the layout has a nonzero size.
+        let ptr = unsafe { alloc(layout) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Self { ptr, capacity },
+            None => handle_alloc_error(layout),
+        }
+    }
+
+    fn layout(capacity: usize) -> Layout {
+        Layout::array::<u8>(capacity).expect("chunk capacity should not overflow")
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // SAFETY: The chunk was allocated with this layout.
+        unsafe { dealloc(self.ptr.as_ptr(), Self::layout(self.capacity)) };
+    }
+}
+
+/// A value in an arena that needs to be dropped.
+struct DropEntry {
+    ptr: NonNull<u8>,
+    drop: unsafe fn(*mut u8),
+}
+
+/// Drop the unaligned value of type `T` at the given pointer.
+///
+/// ## Safety
+/// The pointer must point to a live value of type `T`, which must not be used again.
+unsafe fn drop_unaligned<T>(ptr: *mut u8) {
+    // SAFETY: The caller has ensured that the pointer points to a live value, which is moved out and dropped here.
+    drop(unsafe { ptr.cast::<T>().read_unaligned() });
+}
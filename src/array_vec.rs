@@ -0,0 +1,261 @@
+//! A fixed-capacity vector of packed, unaligned values that does not allocate.
+
+use core::{
+    fmt::{self, Debug, Display},
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ptr, slice,
+};
+
+use crate::{pod::Pod, Unaligned};
+
+/// The error returned when pushing a value into a full [`PackedArrayVec`]. The rejected value is returned inside
+/// the error.
+pub struct CapacityError<T>(T);
+
+impl<T> CapacityError<T> {
+    /// Consume this error and return the value that could not be pushed.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
+
+/// A vector with a fixed capacity of `N` values, whose length and values are stored together in one packed block.
+///
+/// The layout of this type is an unaligned little endian `u32` length, immediately followed by storage for `N`
+/// unaligned values of type `T`. Only the first `len` values are initialized, and only those values are dropped. The
+/// layout does not depend on the target platform, so the capacity `N` must fit into a `u32`. This is checked at
+/// compile time.
+///
+/// ## Example
+/// ```
+/// # use unaligned::array_vec::PackedArrayVec;
+/// let mut vec = PackedArrayVec::<u16, 2>::new();
+/// vec.push(1).unwrap();
+/// vec.push(2).unwrap();
+/// assert!(vec.push(3).is_err());
+/// assert_eq!(Some(2), vec.pop());
+/// assert_eq!(8, core::mem::size_of_val(&vec));
+/// assert_eq!([1, 0, 0, 0], vec.as_bytes()[..4]);
+/// ```
+///
+/// Values are dropped when they are popped, cleared, or when the vector is dropped.
+/// ```
+/// # use unaligned::array_vec::PackedArrayVec;
+/// use std::rc::Rc;
+///
+/// let value = Rc::new(());
+/// let mut vec = PackedArrayVec::<Rc<()>, 4>::new();
+/// for _ in 0..3 {
+///     vec.push(Rc::clone(&value)).unwrap();
+/// }
+/// assert_eq!(4, Rc::strong_count(&value));
+/// drop(vec.pop());
+/// assert_eq!(3, Rc::strong_count(&value));
+/// drop(vec);
+/// assert_eq!(1, Rc::strong_count(&value));
+/// ```
+///
+/// The capacity must fit into a `u32`.
+/// ```compile_fail
+/// # use unaligned::array_vec::PackedArrayVec;
+/// let vec = PackedArrayVec::<(), { u32::MAX as usize + 1 }>::new();
+/// ```
+#[repr(C)]
+pub struct PackedArrayVec<T, const N: usize> {
+    len: Unaligned<u32>,
+    data: Unaligned<[MaybeUninit<T>; N]>,
+}
+
+impl<T, const N: usize> PackedArrayVec<T, N> {
+    const CAPACITY_FITS: () = assert!(N <= u32::MAX as usize, "capacity should fit into a u32");
+
+    /// Construct a new, empty vector.
+    pub const fn new() -> Self {
+        let () = Self::CAPACITY_FITS;
+        Self {
+            len: Unaligned::new(0),
+            // SAFETY: An array of MaybeUninit does not require initialization. The storage is zeroed so that every
+            // byte of the vector is initialized as long as T has no padding, which makes `as_bytes` sound.
+            data: Unaligned::new(unsafe { MaybeUninit::zeroed().assume_init() }),
+        }
+    }
+
+    /// Get the number of values in this vector.
+    pub const fn len(&self) -> usize {
+        u32::from_le(self.len.get()) as usize
+    }
+
+    /// Return `true` if this vector contains no values.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return `true` if this vector is filled to capacity.
+    pub const fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Get the capacity of this vector.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Append a value to the end of this vector. If the vector is full, the value is returned in an error.
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        let len = self.len();
+        if len == N {
+            return Err(CapacityError(value));
+        }
+        // SAFETY: The index is in bounds of the storage, and Unaligned<T> has alignment 1.
+        unsafe { self.data_ptr().add(len).write(Unaligned::new(value)) };
+        self.set_len(len + 1);
+        Ok(())
+    }
+
+    /// Remove the last value from this vector and return it, or return `None` if this vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len().checked_sub(1)?;
+        self.set_len(len);
+        // SAFETY: The value at index len was initialized, and is no longer considered part of the vector.
+        Some(unsafe { self.data_ptr().add(len).read() }.into_inner())
+    }
+
+    /// Remove all values from this vector.
+    pub fn clear(&mut self) {
+        let values: *mut [Unaligned<T>] = self.as_mut_slice();
+        // the length is reset first, so that a panicking destructor leaks values instead of dropping them twice
+        self.set_len(0);
+        // SAFETY: The values were initialized, and are no longer considered part of the vector.
+        unsafe { ptr::drop_in_place(values) };
+    }
+
+    /// Mutably borrow the value at the given index and perform some computation with it. If the index is out of
+    /// bounds, this method returns `None`. See [`Unaligned::with_mut`] for details.
+    pub fn with_mut_at<R, F>(&mut self, index: usize, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.as_mut_slice().get_mut(index).map(|v| v.with_mut(f))
+    }
+
+    /// Iterate over the unaligned values in this vector.
+    pub fn iter(&self) -> slice::Iter<'_, Unaligned<T>> {
+        self.as_slice().iter()
+    }
+
+    /// Mutably iterate over the unaligned values in this vector.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Unaligned<T>> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// View the contents of this vector as a slice of unaligned values.
+    pub fn as_slice(&self) -> &[Unaligned<T>] {
+        // SAFETY: The first len values are initialized, and Unaligned<T> has alignment 1.
+        unsafe { slice::from_raw_parts(self.data.as_ptr().cast(), self.len()) }
+    }
+
+    /// View the contents of this vector as a mutable slice of unaligned values.
+    pub fn as_mut_slice(&mut self) -> &mut [Unaligned<T>] {
+        let len = self.len();
+        // SAFETY: The first len values are initialized, and Unaligned<T> has alignment 1.
+        unsafe { slice::from_raw_parts_mut(self.data_ptr(), len) }
+    }
+
+    /// Convert this vector into an unaligned array, if it is filled to capacity. Otherwise, the vector is returned
+    /// unchanged in an error.
+    pub fn into_array(self) -> Result<Unaligned<[T; N]>, Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: All N values are initialized, so the storage is a valid array. The vector is not dropped, so
+        // ownership of the values is transferred to the array.
+        Ok(unsafe { ptr::read(ptr::addr_of!(this.data).cast()) })
+    }
+
+    fn set_len(&mut self, len: usize) {
+        // the length is at most N, which fits into a u32
+        self.len.set((len as u32).to_le());
+    }
+
+    fn data_ptr(&mut self) -> *mut Unaligned<T> {
+        self.data.as_mut_ptr().cast()
+    }
+}
+
+impl<T: Copy, const N: usize> PackedArrayVec<T, N> {
+    /// Copy the value at the given index, or return `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.as_slice().get(index).map(Unaligned::get)
+    }
+}
+
+impl<T: Pod, const N: usize> PackedArrayVec<T, N> {
+    /// View this entire vector, including its length and unused capacity, as bytes. Unused capacity contains either
+    /// zeroes or values that were previously removed.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The storage was zeroed on construction, and only ever overwritten with values of a Pod type
+        // (which have no padding), so every byte is initialized.
+        unsafe { slice::from_raw_parts((self as *const Self).cast(), mem::size_of::<Self>()) }
+    }
+}
+
+// trait implementations
+
+impl<T, const N: usize> Drop for PackedArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for PackedArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const N: usize> Clone for PackedArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        // SAFETY: The values are Copy, so a bitwise copy of the vector is a valid copy.
+        unsafe { ptr::read(self) }
+    }
+}
+
+impl<T: Copy + Debug, const N: usize> Debug for PackedArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter().map(Unaligned::get)).finish()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PackedArrayVec<T, N> {
+    type Item = &'a Unaligned<T>;
+    type IntoIter = slice::Iter<'a, Unaligned<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut PackedArrayVec<T, N> {
+    type Item = &'a mut Unaligned<T>;
+    type IntoIter = slice::IterMut<'a, Unaligned<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
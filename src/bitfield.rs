@@ -0,0 +1,169 @@
+/// Define a newtype over an [`Unaligned`](crate::Unaligned) unsigned integer with named bit ranges.
+///
+/// Each field is declared with a half-open range of bit positions, where bit 0 is the least significant bit, followed
+/// by the names of its three accessors. For a field declared as `foo: 0..4 => (get_foo, set_foo, with_foo)`, the macro
+/// generates the following accessors.
+/// - `get_foo(&self)` returns the value of the field, shifted down to the least significant bits.
+/// - `set_foo(&mut self, value)` sets the value of the field, leaving the other bits unchanged.
+/// - `with_foo(self, value)` returns a copy with the value of the field replaced.
+///
+/// The accessor names are spelled out rather than derived from the field name, because `macro_rules!` cannot build new
+/// identifiers, and doing so would require a proc-macro dependency such as `paste`. Any names can be used, but the
+/// `get_`, `set_` and `with_` prefixes are the convention in this crate.
+///
+/// Field values are range-checked: `set_foo` and `with_foo` panic if the value does not fit into the field. Field
+/// ranges are checked at compile time to be nonempty and within the bounds of the storage type, and the storage type is
+/// checked to be unsigned.
+///
+/// In addition to the field accessors, the generated type has the constructor `new`, the raw accessors `bits` and
+/// `set_bits`, and implementations of `Clone`, `Copy`, `Default`, `PartialEq`, `Eq`, `Hash`, and `Debug`. Attributes
+/// other than these derives (such as doc comments) are passed through to the generated type.
+///
+/// ## Example
+/// ```
+/// unaligned::bitfield! {
+///     /// The flags of a frame header.
+///     pub struct FrameFlags(u16) {
+///         /// Whether this is the final frame.
+///         pub fin: 0..1 => (get_fin, set_fin, with_fin),
+///         pub opcode: 1..5 => (get_opcode, set_opcode, with_opcode),
+///         pub length: 8..16 => (get_length, set_length, with_length),
+///     }
+/// }
+///
+/// let mut flags = FrameFlags::new(0).with_fin(1).with_opcode(0x9);
+/// flags.set_length(200);
+/// assert_eq!(1, flags.get_fin());
+/// assert_eq!(0x9, flags.get_opcode());
+/// assert_eq!(200, flags.get_length());
+/// assert_eq!(0xc813, flags.bits());
+/// ```
+///
+/// Values that do not fit into a field are rejected.
+/// ```should_panic
+/// # unaligned::bitfield! {
+/// #     pub struct FrameFlags(u16) { pub opcode: 1..5 => (get_opcode, set_opcode, with_opcode) }
+/// # }
+/// let flags = FrameFlags::new(0).with_opcode(0x10);
+/// ```
+///
+/// Signed storage types are rejected at compile time.
+/// ```compile_fail
+/// unaligned::bitfield! {
+///     pub struct Signed(i16) { pub low: 0..4 => (get_low, set_low, with_low) }
+/// }
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($int:ty) {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $lo:literal..$hi:literal => ($get:ident, $set:ident, $with:ident)
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        $vis struct $name($crate::Unaligned<$int>);
+
+        const _: () = {
+            assert!(
+                <$int>::MIN == 0,
+                concat!("storage type of bitfield `", stringify!($name), "` should be an unsigned integer"),
+            );
+            $(
+                assert!(
+                    $lo < $hi && $hi <= <$int>::BITS,
+                    concat!("bit range of field `", stringify!($field), "` is invalid"),
+                );
+            )*
+        };
+
+        impl $name {
+            /// Construct a new bitfield with the given raw bits.
+            pub const fn new(bits: $int) -> Self {
+                Self($crate::Unaligned::new(bits))
+            }
+
+            /// Get the raw bits of this bitfield.
+            pub const fn bits(&self) -> $int {
+                self.0.get()
+            }
+
+            /// Set the raw bits of this bitfield.
+            pub fn set_bits(&mut self, bits: $int) {
+                self.0.set(bits);
+            }
+
+            $(
+                $(#[$field_attr])*
+                #[doc = ""]
+                #[doc = concat!("Get the value of the `", stringify!($field), "` field (bits ", stringify!($lo), "..", stringify!($hi), ").")]
+                $field_vis fn $get(&self) -> $int {
+                    (self.bits() >> $lo) & (<$int>::MAX >> (<$int>::BITS - ($hi - $lo)))
+                }
+
+                #[doc = concat!("Set the value of the `", stringify!($field), "` field (bits ", stringify!($lo), "..", stringify!($hi), ").")]
+                ///
+                /// ## Panics
+                /// This method panics if the value does not fit into the field.
+                $field_vis fn $set(&mut self, value: $int) {
+                    let mask = <$int>::MAX >> (<$int>::BITS - ($hi - $lo));
+                    assert!(
+                        value <= mask,
+                        concat!("value should fit into field `", stringify!($field), "`"),
+                    );
+                    self.set_bits((self.bits() & !(mask << $lo)) | (value << $lo));
+                }
+
+                #[doc = concat!("Return a copy of this bitfield with the `", stringify!($field), "` field (bits ", stringify!($lo), "..", stringify!($hi), ") replaced.")]
+                ///
+                /// ## Panics
+                /// This method panics if the value does not fit into the field.
+                #[must_use]
+                $field_vis fn $with(mut self, value: $int) -> Self {
+                    self.$set(value);
+                    self
+                }
+            )*
+        }
+
+        impl ::core::clone::Clone for $name {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl ::core::marker::Copy for $name {}
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl ::core::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.bits() == other.bits()
+            }
+        }
+
+        impl ::core::cmp::Eq for $name {}
+
+        impl ::core::hash::Hash for $name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.bits(), state);
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$get()))*
+                    .finish()
+            }
+        }
+    };
+}
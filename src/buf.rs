@@ -0,0 +1,166 @@
+//! Integration with the [`bytes`] crate.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+};
+
+use ::bytes::{Buf, BufMut, Bytes};
+
+use crate::{cursor::ShortBuffer, pod::Pod, Unaligned};
+
+/// Extension methods for reading unaligned values from a [`Buf`]. This trait is implemented for every buffer.
+///
+/// ## Example
+/// ```
+/// # use unaligned::buf::BufUnalignedExt;
+/// let mut buf: &[u8] = &[1, 2, 3, 4];
+/// assert_eq!([1, 2, 3], buf.get_unaligned::<[u8; 3]>().get());
+/// assert!(buf.try_get_unaligned::<u16>().is_err());
+/// ```
+pub trait BufUnalignedExt: Buf {
+    /// Read an unaligned value, stored in native byte order.
+    ///
+    /// ## Panics
+    /// This method panics if there are not enough bytes remaining in the buffer.
+    fn get_unaligned<T: Pod>(&mut self) -> Unaligned<T> {
+        self.try_get_unaligned()
+            .expect("buffer should have enough bytes remaining")
+    }
+
+    /// Read an unaligned value, stored in native byte order. If there are not enough bytes remaining in the buffer,
+    /// this method returns an error and the buffer is not advanced.
+    fn try_get_unaligned<T: Pod>(&mut self) -> Result<Unaligned<T>, ShortBuffer> {
+        // SAFETY: T is a Pod type, so all zeroes is a valid value.
+        let mut value: Unaligned<T> = unsafe { mem::zeroed() };
+        self.try_copy_to_unaligned_slice(core::slice::from_mut(&mut value))?;
+        Ok(value)
+    }
+
+    /// Copy bytes from the buffer to fill the given slice of unaligned values. If there are not enough bytes
+    /// remaining in the buffer, this method returns an error and the buffer is not advanced.
+    fn try_copy_to_unaligned_slice<T: Pod>(
+        &mut self,
+        values: &mut [Unaligned<T>],
+    ) -> Result<(), ShortBuffer> {
+        let bytes = Unaligned::slice_as_bytes_mut(values);
+        if self.remaining() < bytes.len() {
+            return Err(ShortBuffer::new(bytes.len(), self.remaining()));
+        }
+        self.copy_to_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<B: Buf + ?Sized> BufUnalignedExt for B {}
+
+/// Extension methods for writing unaligned values to a [`BufMut`]. This trait is implemented for every buffer.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{buf::BufMutUnalignedExt, Unaligned};
+/// let mut buf = Vec::new();
+/// buf.put_unaligned(&Unaligned::new([1u8, 2]));
+/// buf.put_unaligned_slice(&[Unaligned::new(3u8)]);
+/// assert_eq!([1, 2, 3], *buf);
+/// ```
+pub trait BufMutUnalignedExt: BufMut {
+    /// Write an unaligned value in native byte order.
+    ///
+    /// ## Panics
+    /// This method panics if there is not enough capacity remaining in the buffer.
+    fn put_unaligned<T: Pod>(&mut self, value: &Unaligned<T>) {
+        self.put_slice(value.as_bytes());
+    }
+
+    /// Write a slice of unaligned values in native byte order.
+    ///
+    /// ## Panics
+    /// This method panics if there is not enough capacity remaining in the buffer.
+    fn put_unaligned_slice<T: Pod>(&mut self, values: &[Unaligned<T>]) {
+        self.put_slice(Unaligned::slice_as_bytes(values));
+    }
+}
+
+impl<B: BufMut + ?Sized> BufMutUnalignedExt for B {}
+
+/// A zero-copy view of [`Bytes`] as a slice of unaligned values.
+///
+/// ## Example
+/// ```
+/// # use bytes::Bytes;
+/// # use unaligned::buf::UnalignedBytes;
+/// let bytes = Bytes::from_static(&[1, 0, 2, 0, 3, 0]);
+/// let values = UnalignedBytes::<u16>::new(bytes).unwrap();
+/// assert_eq!(3, values.len());
+/// assert_eq!(2, u16::from_le(values[1].get()));
+/// ```
+pub struct UnalignedBytes<T> {
+    bytes: Bytes,
+    marker: PhantomData<T>,
+}
+
+impl<T: Pod> UnalignedBytes<T> {
+    /// View the given bytes as a slice of unaligned values. If the length of `bytes` is not a multiple of the size
+    /// of `T`, or if `T` is zero-sized, the bytes are returned in an error.
+    pub fn new(bytes: Bytes) -> Result<Self, Bytes> {
+        if Unaligned::<T>::slice_from_bytes(&bytes).is_none() {
+            return Err(bytes);
+        }
+        Ok(Self {
+            bytes,
+            marker: PhantomData,
+        })
+    }
+
+    /// Split off the first `len` values into a new view. This operation is O(1).
+    ///
+    /// ## Panics
+    /// This method panics if `len` is greater than the number of values in this view.
+    pub fn split_to(&mut self, len: usize) -> Self {
+        let byte_len = len
+            .checked_mul(mem::size_of::<T>())
+            .filter(|&byte_len| byte_len <= self.bytes.len())
+            .expect("split length should be at most the number of values");
+        Self {
+            bytes: self.bytes.split_to(byte_len),
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the underlying bytes of this view.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Consume this view and return the underlying bytes.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl<T: Pod> Deref for UnalignedBytes<T> {
+    type Target = [Unaligned<T>];
+
+    fn deref(&self) -> &Self::Target {
+        Unaligned::slice_from_bytes(&self.bytes)
+            .expect("length should be a multiple of the size of T")
+    }
+}
+
+impl<T> Clone for UnalignedBytes<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for UnalignedBytes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UnalignedBytes").field(&self.bytes).finish()
+    }
+}
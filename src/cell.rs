@@ -14,6 +14,8 @@ use self::opt::OptUnaligned;
 
 /// Private module that defines an option type for use in the cell.
 mod opt;
+/// Private module that defines atomic-style operations on cells containing integers.
+mod int;
 
 /// A value borrowed from an [`UnalignedCell`].
 pub struct RefMut<'a, T> {
@@ -131,6 +133,31 @@ impl<T> UnalignedCell<T> {
         })
     }
 
+    /// Mutably borrow the contents of this cell and another at the same time. If either is already borrowed, or if
+    /// both arguments refer to the same cell, this method returns an error and neither cell is borrowed.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::cell::UnalignedCell;
+    /// let a = UnalignedCell::new(1);
+    /// let b = UnalignedCell::new(2);
+    /// let (mut x, mut y) = a.borrow_pair(&b).unwrap();
+    /// *x += *y;
+    /// *y = 0;
+    /// drop((x, y));
+    /// assert!(b.borrow_pair(&b).is_err());
+    /// assert_eq!((3, 0), (a.into_inner(), b.into_inner()));
+    /// ```
+    pub fn borrow_pair<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> Result<(RefMut<'a, T>, RefMut<'a, T>), BorrowError> {
+        // if the second borrow fails, the first is released when it is dropped
+        let first = self.try_borrow()?;
+        let second = other.try_borrow()?;
+        Ok((first, second))
+    }
+
     /// Get a mutable reference to the unaligned contents. Because this method takes `self` by mutable reference,
     /// no runtime checks are needed.
     /// 
@@ -152,7 +179,8 @@ impl<T> UnalignedCell<T> {
         mem::swap(&mut *self.borrow(), &mut *other.borrow());
     }
 
-    /// Replace the contents of this cell with the given value, and return the previous value.
+    /// Replace the contents of this cell with the given value, and return the previous value. This is the equivalent
+    /// of the `swap` method of the standard atomic types.
     /// 
     /// ## Panics
     /// This method panics if the value is already borrowed.
@@ -191,6 +219,69 @@ impl<T: Default> UnalignedCell<T> {
     }
 }
 
+/// Atomic-style operations for cells containing `Copy` values. These methods mirror the API of the standard atomic
+/// types (without memory orderings), so that code written against them can later switch to a thread-safe cell.
+///
+/// The atomic `swap(val)` method has no counterpart here: [`UnalignedCell::swap`] already exists and exchanges the
+/// contents of two cells, like [`RefCell::swap`](core::cell::RefCell::swap). Use [`UnalignedCell::replace`], which
+/// stores a new value and returns the previous one, instead.
+impl<T: Copy> UnalignedCell<T> {
+    /// Fetch the current value and apply a function to it that optionally returns a new value. If the function
+    /// returns `Some`, the new value is stored and `Ok(previous_value)` is returned. Otherwise, the value is left
+    /// unchanged and `Err(previous_value)` is returned.
+    ///
+    /// ## Panics
+    /// This method panics if the value is already borrowed.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::cell::UnalignedCell;
+    /// let cell = UnalignedCell::new(7u32);
+    /// assert_eq!(Ok(7), cell.fetch_update(|v| v.checked_mul(2)));
+    /// assert_eq!(Err(14), cell.fetch_update(|_| None));
+    /// assert_eq!(14, cell.into_inner());
+    /// ```
+    pub fn fetch_update<F>(&self, f: F) -> Result<T, T>
+    where
+        F: FnOnce(T) -> Option<T>,
+    {
+        let mut updated = false;
+        let previous = self.replace_with(|val| match f(*val) {
+            Some(new_val) => {
+                updated = true;
+                new_val
+            }
+            None => *val,
+        });
+        if updated {
+            Ok(previous)
+        } else {
+            Err(previous)
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> UnalignedCell<T> {
+    /// Store a new value if the current value is equal to `current`. The return value is `Ok(previous_value)`
+    /// if the value was updated, and `Err(previous_value)` otherwise.
+    ///
+    /// ## Panics
+    /// This method panics if the value is already borrowed.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::cell::UnalignedCell;
+    /// let cell = UnalignedCell::new(5u32);
+    /// assert_eq!(Ok(5), cell.compare_exchange(5, 10));
+    /// // the value is not 6, so it is left unchanged and returned in `Err`
+    /// assert_eq!(Err(10), cell.compare_exchange(6, 12));
+    /// assert_eq!(10, cell.into_inner());
+    /// ```
+    pub fn compare_exchange(&self, current: T, new: T) -> Result<T, T> {
+        self.fetch_update(|val| if val == current { Some(new) } else { None })
+    }
+}
+
 // trait implementations
 
 impl<T> From<T> for UnalignedCell<T> {
@@ -0,0 +1,92 @@
+use super::UnalignedCell;
+
+/// Implements atomic-style read-modify-write operations for cells containing the given integer types.
+macro_rules! impl_fetch_ops {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl UnalignedCell<$int> {
+                /// Add to the current value, returning the previous value. This operation wraps around on overflow.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                ///
+                /// ## Example
+                /// ```
+                /// # use unaligned::cell::UnalignedCell;
+                #[doc = concat!("let cell = UnalignedCell::<", stringify!($int), ">::new(42);")]
+                /// assert_eq!(42, cell.fetch_add(28));
+                /// assert_eq!(70, cell.into_inner());
+                /// ```
+                pub fn fetch_add(&self, value: $int) -> $int {
+                    self.replace_with(|v| v.wrapping_add(value))
+                }
+
+                /// Subtract from the current value, returning the previous value. This operation wraps around on overflow.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                ///
+                /// ## Example
+                /// ```
+                /// # use unaligned::cell::UnalignedCell;
+                #[doc = concat!("let cell = UnalignedCell::new(", stringify!($int), "::MIN);")]
+                #[doc = concat!("assert_eq!(", stringify!($int), "::MIN, cell.fetch_sub(1));")]
+                #[doc = concat!("assert_eq!(", stringify!($int), "::MAX, cell.into_inner());")]
+                /// ```
+                pub fn fetch_sub(&self, value: $int) -> $int {
+                    self.replace_with(|v| v.wrapping_sub(value))
+                }
+
+                /// Bitwise "and" with the current value, returning the previous value.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                pub fn fetch_and(&self, value: $int) -> $int {
+                    self.replace_with(|v| *v & value)
+                }
+
+                /// Bitwise "nand" with the current value, returning the previous value.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                pub fn fetch_nand(&self, value: $int) -> $int {
+                    self.replace_with(|v| !(*v & value))
+                }
+
+                /// Bitwise "or" with the current value, returning the previous value.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                pub fn fetch_or(&self, value: $int) -> $int {
+                    self.replace_with(|v| *v | value)
+                }
+
+                /// Bitwise "xor" with the current value, returning the previous value.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                pub fn fetch_xor(&self, value: $int) -> $int {
+                    self.replace_with(|v| *v ^ value)
+                }
+
+                /// Set the current value to the maximum of itself and the given value, returning the previous value.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                pub fn fetch_max(&self, value: $int) -> $int {
+                    self.replace_with(|v| <$int>::max(*v, value))
+                }
+
+                /// Set the current value to the minimum of itself and the given value, returning the previous value.
+                ///
+                /// ## Panics
+                /// This method panics if the value is already borrowed.
+                pub fn fetch_min(&self, value: $int) -> $int {
+                    self.replace_with(|v| <$int>::min(*v, value))
+                }
+            }
+        )*
+    };
+}
+
+impl_fetch_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
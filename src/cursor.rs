@@ -0,0 +1,226 @@
+//! Cursors for reading and writing typed unaligned values in byte buffers.
+
+use core::{
+    fmt::{self, Display},
+    mem, slice,
+};
+
+use crate::{
+    endian::{BigEndian, ByteOrder, EndianInt, LittleEndian},
+    pod::Pod,
+    Unaligned,
+};
+
+/// The error returned when a buffer is too short for a read or write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShortBuffer {
+    needed: usize,
+    remaining: usize,
+}
+
+impl ShortBuffer {
+    pub(crate) const fn new(needed: usize, remaining: usize) -> Self {
+        Self { needed, remaining }
+    }
+
+    /// Get the number of bytes the operation needed.
+    pub fn needed(&self) -> usize {
+        self.needed
+    }
+
+    /// Get the number of bytes that remained in the buffer.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShortBuffer {}
+
+impl Display for ShortBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer too short: needed {} bytes, but only {} remain",
+            self.needed, self.remaining
+        )
+    }
+}
+
+/// A cursor that reads typed values from a byte buffer.
+///
+/// ## Example
+/// ```
+/// # use unaligned::cursor::UnalignedReader;
+/// let bytes = [7, 0x12, 0x34, 1, 0, 0, 0];
+/// let mut reader = UnalignedReader::new(&bytes);
+/// assert_eq!(Ok(7), reader.read::<u8>());
+/// assert_eq!(Ok(0x1234), reader.read_be::<u16>());
+/// let value = reader.read_ref::<u32>().unwrap();
+/// assert_eq!(1, u32::from_le(value.get()));
+/// assert!(reader.read::<u8>().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnalignedReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> UnalignedReader<'a> {
+    /// Construct a new reader at the start of the given buffer.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Get the number of bytes read so far.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the number of bytes remaining in the buffer.
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Get the bytes remaining in the buffer, without advancing the reader.
+    pub const fn remaining_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Read the given number of bytes.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ShortBuffer> {
+        if len > self.bytes.len() {
+            return Err(ShortBuffer::new(len, self.bytes.len()));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        self.position += len;
+        Ok(head)
+    }
+
+    /// Skip over the given number of bytes.
+    pub fn skip(&mut self, len: usize) -> Result<(), ShortBuffer> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Read a reference to an unaligned value in the buffer.
+    pub fn read_ref<T: Pod>(&mut self) -> Result<&'a Unaligned<T>, ShortBuffer> {
+        let bytes = self.read_bytes(mem::size_of::<T>())?;
+        Ok(Unaligned::from_bytes(bytes).expect("length should be the size of T"))
+    }
+
+    /// Read a reference to a slice of `len` unaligned values in the buffer.
+    pub fn read_slice<T: Pod>(&mut self, len: usize) -> Result<&'a [Unaligned<T>], ShortBuffer> {
+        let byte_len = mem::size_of::<T>().saturating_mul(len);
+        let bytes = self.read_bytes(byte_len)?;
+        // SAFETY: The length is exactly len values, Unaligned<T> has alignment 1, and T is a Pod type.
+        Ok(unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), len) })
+    }
+
+    /// Read a value, stored in native byte order.
+    pub fn read<T: Pod>(&mut self) -> Result<T, ShortBuffer> {
+        self.read_ref().map(Unaligned::get)
+    }
+
+    /// Read a value without advancing the reader.
+    pub fn peek<T: Pod>(&self) -> Result<T, ShortBuffer> {
+        self.clone().read()
+    }
+
+    /// Read an integer stored in the given byte order.
+    pub fn read_int<I: EndianInt, E: ByteOrder>(&mut self) -> Result<I, ShortBuffer> {
+        self.read::<I>().map(I::from_order::<E>)
+    }
+
+    /// Read an integer stored in little endian byte order.
+    pub fn read_le<I: EndianInt>(&mut self) -> Result<I, ShortBuffer> {
+        self.read_int::<I, LittleEndian>()
+    }
+
+    /// Read an integer stored in big endian byte order.
+    pub fn read_be<I: EndianInt>(&mut self) -> Result<I, ShortBuffer> {
+        self.read_int::<I, BigEndian>()
+    }
+}
+
+/// A cursor that writes typed values into a byte buffer.
+///
+/// ## Example
+/// ```
+/// # use unaligned::cursor::UnalignedWriter;
+/// let mut bytes = [0; 7];
+/// let mut writer = UnalignedWriter::new(&mut bytes);
+/// let len = writer.reserve::<u16>().unwrap();
+/// writer.write_be(0x1234u32).unwrap();
+/// writer.write(7u8).unwrap();
+/// len.set(u16::to_le(writer.position() as u16));
+/// assert!(writer.write(0u8).is_err());
+/// assert_eq!([7, 0, 0, 0, 0x12, 0x34, 7], bytes);
+/// ```
+#[derive(Debug)]
+pub struct UnalignedWriter<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> UnalignedWriter<'a> {
+    /// Construct a new writer at the start of the given buffer.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Get the number of bytes written so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the number of bytes remaining in the buffer.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Reserve the given number of bytes, returning them for writing later.
+    pub fn reserve_bytes(&mut self, len: usize) -> Result<&'a mut [u8], ShortBuffer> {
+        if len > self.bytes.len() {
+            return Err(ShortBuffer::new(len, self.bytes.len()));
+        }
+        let (head, tail) = mem::take(&mut self.bytes).split_at_mut(len);
+        self.bytes = tail;
+        self.position += len;
+        Ok(head)
+    }
+
+    /// Reserve space for an unaligned value, returning it for writing later. The reserved space initially contains
+    /// whatever bytes were in the buffer.
+    pub fn reserve<T: Pod>(&mut self) -> Result<&'a mut Unaligned<T>, ShortBuffer> {
+        let bytes = self.reserve_bytes(mem::size_of::<T>())?;
+        Ok(Unaligned::from_bytes_mut(bytes).expect("length should be the size of T"))
+    }
+
+    /// Write the given bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), ShortBuffer> {
+        self.reserve_bytes(bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Write a value in native byte order.
+    pub fn write<T: Pod>(&mut self, value: T) -> Result<(), ShortBuffer> {
+        self.reserve()?.set(value);
+        Ok(())
+    }
+
+    /// Write an integer in the given byte order.
+    pub fn write_int<I: EndianInt, E: ByteOrder>(&mut self, value: I) -> Result<(), ShortBuffer> {
+        self.write(value.to_order::<E>())
+    }
+
+    /// Write an integer in little endian byte order.
+    pub fn write_le<I: EndianInt>(&mut self, value: I) -> Result<(), ShortBuffer> {
+        self.write_int::<I, LittleEndian>(value)
+    }
+
+    /// Write an integer in big endian byte order.
+    pub fn write_be<I: EndianInt>(&mut self, value: I) -> Result<(), ShortBuffer> {
+        self.write_int::<I, BigEndian>(value)
+    }
+}
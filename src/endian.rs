@@ -0,0 +1,116 @@
+//! Byte order markers for endian-aware unaligned types.
+
+use core::{fmt::Debug, hash::Hash};
+
+use crate::pod::Pod;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A byte order in which integers may be stored. This trait is sealed, and is implemented only by [`LittleEndian`] and
+/// [`BigEndian`].
+pub trait ByteOrder:
+    sealed::Sealed + Copy + Default + Debug + Eq + Ord + Hash + Send + Sync + 'static
+{
+    /// Decode an unsigned integer from the given bytes. The result is zero-extended.
+    ///
+    /// ## Panics
+    /// This function panics if `N` is greater than 16.
+    fn read_uint<const N: usize>(bytes: [u8; N]) -> u128;
+
+    /// Encode the least significant `N` bytes of the given integer.
+    ///
+    /// ## Panics
+    /// This function panics if `N` is greater than 16.
+    fn write_uint<const N: usize>(value: u128) -> [u8; N];
+}
+
+/// Little endian byte order, where the least significant byte is stored first.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LittleEndian;
+
+/// Big endian byte order, where the most significant byte is stored first.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigEndian;
+
+/// The byte order of the target platform.
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// The byte order of the target platform.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// Network byte order, which is big endian.
+pub type NetworkEndian = BigEndian;
+
+impl sealed::Sealed for LittleEndian {}
+
+impl ByteOrder for LittleEndian {
+    fn read_uint<const N: usize>(bytes: [u8; N]) -> u128 {
+        let mut buf = [0; 16];
+        buf[..N].copy_from_slice(&bytes);
+        u128::from_le_bytes(buf)
+    }
+
+    fn write_uint<const N: usize>(value: u128) -> [u8; N] {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&value.to_le_bytes()[..N]);
+        bytes
+    }
+}
+
+impl sealed::Sealed for BigEndian {}
+
+impl ByteOrder for BigEndian {
+    fn read_uint<const N: usize>(bytes: [u8; N]) -> u128 {
+        let mut buf = [0; 16];
+        buf[16 - N..].copy_from_slice(&bytes);
+        u128::from_be_bytes(buf)
+    }
+
+    fn write_uint<const N: usize>(value: u128) -> [u8; N] {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&value.to_be_bytes()[16 - N..]);
+        bytes
+    }
+}
+
+/// A primitive integer type that can be converted to and from a given byte order. This trait is sealed, and is
+/// implemented only for the primitive integer types.
+///
+/// ## Example
+/// ```
+/// # use unaligned::endian::{BigEndian, EndianInt};
+/// let raw = u32::from_ne_bytes([0x12, 0x34, 0x56, 0x78]);
+/// assert_eq!(0x12345678, u32::from_order::<BigEndian>(raw));
+/// assert_eq!([0x12, 0x34, 0x56, 0x78], 0x12345678u32.to_order::<BigEndian>().to_ne_bytes());
+/// ```
+pub trait EndianInt: sealed::Sealed + Pod {
+    /// Interpret the in-memory representation of the given integer as an integer stored in byte order `E`.
+    fn from_order<E: ByteOrder>(raw: Self) -> Self;
+
+    /// Get the integer whose in-memory representation is this integer stored in byte order `E`.
+    fn to_order<E: ByteOrder>(self) -> Self;
+}
+
+macro_rules! impl_endian_int {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $int {}
+
+            impl EndianInt for $int {
+                fn from_order<E: ByteOrder>(raw: Self) -> Self {
+                    E::read_uint(raw.to_ne_bytes()) as Self
+                }
+
+                fn to_order<E: ByteOrder>(self) -> Self {
+                    Self::from_ne_bytes(E::write_uint(self as u128))
+                }
+            }
+        )*
+    };
+}
+
+impl_endian_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
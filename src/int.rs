@@ -0,0 +1,246 @@
+//! Fixed-width integers with sizes that have no corresponding primitive type.
+//!
+//! Each type in this module is stored as exactly as many bytes as its width requires, in the byte order given by its
+//! [`ByteOrder`] parameter. Like [`Unaligned<[u8; N]>`](crate::Unaligned), these types have an alignment of 1, so they
+//! can be placed at any offset inside packed records.
+//!
+//! ## Example
+//! ```
+//! # use unaligned::int::{I24Le, U24Be, U24Le};
+//! let value = U24Le::new(0x123456).unwrap();
+//! assert_eq!([0x56, 0x34, 0x12], value.to_bytes());
+//! assert_eq!(0x123456, U24Be::from_bytes([0x12, 0x34, 0x56]).get());
+//! assert!(U24Le::new(1 << 24).is_none());
+//! assert_eq!(-2, I24Le::from_bytes([0xfe, 0xff, 0xff]).get());
+//! assert_eq!(I24Le::MIN, (I24Le::new(I24Le::MAX).unwrap().wrapping_add(I24Le::new(1).unwrap())).get());
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
+};
+
+use crate::endian::{BigEndian, ByteOrder, LittleEndian};
+
+/// The error returned when a value does not fit into the target integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromIntError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntError {}
+
+impl Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+macro_rules! odd_int {
+    ($(#[$attr:meta])* $name:ident($native:ty, $size:literal), $le:ident, $be:ident) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+        pub struct $name<E: ByteOrder> {
+            bytes: [u8; $size],
+            order: PhantomData<E>,
+        }
+
+        #[doc = concat!("A little endian [`", stringify!($name), "`].")]
+        pub type $le = $name<LittleEndian>;
+
+        #[doc = concat!("A big endian [`", stringify!($name), "`].")]
+        pub type $be = $name<BigEndian>;
+
+        impl<E: ByteOrder> $name<E> {
+            /// The size of this integer type in bits.
+            pub const BITS: u32 = $size * 8;
+
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: $native = <$native>::MIN >> Self::SHIFT;
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: $native = <$native>::MAX >> Self::SHIFT;
+
+            const SHIFT: u32 = <$native>::BITS - Self::BITS;
+
+            /// Construct a new integer with the given value, or return `None` if the value is out of range.
+            pub fn new(value: $native) -> Option<Self> {
+                if Self::truncate(value) == value {
+                    Some(Self::new_wrapping(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Construct a new integer with the given value, truncating any bits that do not fit.
+            pub fn new_wrapping(value: $native) -> Self {
+                Self::from_bytes(E::write_uint(value as u128))
+            }
+
+            /// Construct a new integer from its byte representation.
+            pub const fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self {
+                    bytes,
+                    order: PhantomData,
+                }
+            }
+
+            /// Get the byte representation of this integer.
+            pub const fn to_bytes(self) -> [u8; $size] {
+                self.bytes
+            }
+
+            /// Get the value of this integer.
+            pub fn get(&self) -> $native {
+                Self::truncate(E::read_uint(self.bytes) as $native)
+            }
+
+            /// Set the value of this integer.
+            ///
+            /// ## Panics
+            /// This method panics if the value is out of range.
+            pub fn set(&mut self, value: $native) {
+                *self = Self::new(value).expect("value should be in range");
+            }
+
+            /// Checked integer addition. Returns `None` if overflow occurred.
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.get().checked_add(rhs.get()).and_then(Self::new)
+            }
+
+            /// Checked integer subtraction. Returns `None` if overflow occurred.
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.get().checked_sub(rhs.get()).and_then(Self::new)
+            }
+
+            /// Checked integer multiplication. Returns `None` if overflow occurred.
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.get().checked_mul(rhs.get()).and_then(Self::new)
+            }
+
+            /// Checked integer division. Returns `None` if `rhs` is zero or overflow occurred.
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                self.get().checked_div(rhs.get()).and_then(Self::new)
+            }
+
+            /// Checked integer remainder. Returns `None` if `rhs` is zero or overflow occurred.
+            pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+                self.get().checked_rem(rhs.get()).and_then(Self::new)
+            }
+
+            /// Wrapping integer addition, wrapping around at the boundary of the type.
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                Self::new_wrapping(self.get().wrapping_add(rhs.get()))
+            }
+
+            /// Wrapping integer subtraction, wrapping around at the boundary of the type.
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                Self::new_wrapping(self.get().wrapping_sub(rhs.get()))
+            }
+
+            /// Wrapping integer multiplication, wrapping around at the boundary of the type.
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                Self::new_wrapping(self.get().wrapping_mul(rhs.get()))
+            }
+
+            /// Truncate a native integer to the width of this type, sign-extending signed values.
+            fn truncate(value: $native) -> $native {
+                (value << Self::SHIFT) >> Self::SHIFT
+            }
+        }
+
+        impl<E: ByteOrder> From<$name<E>> for $native {
+            fn from(value: $name<E>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<E: ByteOrder> TryFrom<$native> for $name<E> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: $native) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(TryFromIntError)
+            }
+        }
+
+        impl<E: ByteOrder> PartialOrd for $name<E> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<E: ByteOrder> Ord for $name<E> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl<E: ByteOrder> Debug for $name<E> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl<E: ByteOrder> Display for $name<E> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.get(), f)
+            }
+        }
+
+        odd_int!(@op $name, Add, add, AddAssign, add_assign, checked_add, "attempt to add with overflow");
+        odd_int!(@op $name, Sub, sub, SubAssign, sub_assign, checked_sub, "attempt to subtract with overflow");
+        odd_int!(@op $name, Mul, mul, MulAssign, mul_assign, checked_mul, "attempt to multiply with overflow");
+        odd_int!(@op $name, Div, div, DivAssign, div_assign, checked_div, "attempt to divide by zero or with overflow");
+        odd_int!(@op $name, Rem, rem, RemAssign, rem_assign, checked_rem, "attempt to calculate the remainder with a divisor of zero or with overflow");
+    };
+    (@op $name:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $checked:ident, $msg:literal) => {
+        impl<E: ByteOrder> $op for $name<E> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl<E: ByteOrder> $op_assign for $name<E> {
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+odd_int!(
+    /// A 24-bit unsigned integer, stored as 3 bytes.
+    U24(u32, 3), U24Le, U24Be
+);
+odd_int!(
+    /// A 24-bit signed integer, stored as 3 bytes.
+    I24(i32, 3), I24Le, I24Be
+);
+odd_int!(
+    /// A 40-bit unsigned integer, stored as 5 bytes.
+    U40(u64, 5), U40Le, U40Be
+);
+odd_int!(
+    /// A 40-bit signed integer, stored as 5 bytes.
+    I40(i64, 5), I40Le, I40Be
+);
+odd_int!(
+    /// A 48-bit unsigned integer, stored as 6 bytes.
+    U48(u64, 6), U48Le, U48Be
+);
+odd_int!(
+    /// A 48-bit signed integer, stored as 6 bytes.
+    I48(i64, 6), I48Le, I48Be
+);
+odd_int!(
+    /// A 56-bit unsigned integer, stored as 7 bytes.
+    U56(u64, 7), U56Le, U56Be
+);
+odd_int!(
+    /// A 56-bit signed integer, stored as 7 bytes.
+    I56(i64, 7), I56Le, I56Be
+);
@@ -0,0 +1,93 @@
+//! Extension traits for reading and writing unaligned values with [`std::io`].
+
+use std::io::{self, Read, Write};
+
+use core::mem;
+
+use crate::{
+    endian::{BigEndian, ByteOrder, EndianInt, LittleEndian},
+    pod::Pod,
+    Unaligned,
+};
+
+/// Extension methods for reading unaligned values from a [`Read`]er. This trait is implemented for every reader.
+///
+/// ## Example
+/// ```
+/// # use unaligned::io::ReadUnalignedExt;
+/// let mut bytes: &[u8] = &[0x12, 0x34, 1, 2, 3];
+/// assert_eq!(0x1234, bytes.read_be::<u16>().unwrap());
+/// assert_eq!([1, 2, 3], bytes.read_unaligned::<[u8; 3]>().unwrap().get());
+/// assert!(bytes.read_unaligned::<u8>().is_err());
+/// ```
+pub trait ReadUnalignedExt: Read {
+    /// Read an unaligned value, stored in native byte order.
+    fn read_unaligned<T: Pod>(&mut self) -> io::Result<Unaligned<T>> {
+        // SAFETY: T is a Pod type, so all zeroes is a valid value.
+        let mut value: Unaligned<T> = unsafe { mem::zeroed() };
+        self.read_exact(value.as_bytes_mut())?;
+        Ok(value)
+    }
+
+    /// Read exactly enough bytes to fill the given slice of unaligned values.
+    fn read_exact_into<T: Pod>(&mut self, values: &mut [Unaligned<T>]) -> io::Result<()> {
+        self.read_exact(Unaligned::slice_as_bytes_mut(values))
+    }
+
+    /// Read an integer stored in the given byte order.
+    fn read_int<I: EndianInt, E: ByteOrder>(&mut self) -> io::Result<I> {
+        self.read_unaligned::<I>()
+            .map(|value| I::from_order::<E>(value.get()))
+    }
+
+    /// Read an integer stored in little endian byte order.
+    fn read_le<I: EndianInt>(&mut self) -> io::Result<I> {
+        self.read_int::<I, LittleEndian>()
+    }
+
+    /// Read an integer stored in big endian byte order.
+    fn read_be<I: EndianInt>(&mut self) -> io::Result<I> {
+        self.read_int::<I, BigEndian>()
+    }
+}
+
+impl<R: Read + ?Sized> ReadUnalignedExt for R {}
+
+/// Extension methods for writing unaligned values to a [`Write`]r. This trait is implemented for every writer.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{io::WriteUnalignedExt, Unaligned};
+/// let mut bytes = Vec::new();
+/// bytes.write_le(0x1234u16).unwrap();
+/// bytes.write_unaligned_slice(&[Unaligned::new(1u8), Unaligned::new(2)]).unwrap();
+/// assert_eq!([0x34, 0x12, 1, 2], *bytes);
+/// ```
+pub trait WriteUnalignedExt: Write {
+    /// Write an unaligned value in native byte order.
+    fn write_unaligned<T: Pod>(&mut self, value: &Unaligned<T>) -> io::Result<()> {
+        self.write_all(value.as_bytes())
+    }
+
+    /// Write a slice of unaligned values in native byte order.
+    fn write_unaligned_slice<T: Pod>(&mut self, values: &[Unaligned<T>]) -> io::Result<()> {
+        self.write_all(Unaligned::slice_as_bytes(values))
+    }
+
+    /// Write an integer in the given byte order.
+    fn write_int<I: EndianInt, E: ByteOrder>(&mut self, value: I) -> io::Result<()> {
+        self.write_unaligned(&Unaligned::new(value.to_order::<E>()))
+    }
+
+    /// Write an integer in little endian byte order.
+    fn write_le<I: EndianInt>(&mut self, value: I) -> io::Result<()> {
+        self.write_int::<I, LittleEndian>(value)
+    }
+
+    /// Write an integer in big endian byte order.
+    fn write_be<I: EndianInt>(&mut self, value: I) -> io::Result<()> {
+        self.write_int::<I, BigEndian>(value)
+    }
+}
+
+impl<W: Write + ?Sized> WriteUnalignedExt for W {}
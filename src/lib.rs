@@ -17,7 +17,9 @@
 //! using the power of interior mutability.
 //! 
 //! This crate is `#![no_std]` by default. The `std` feature can be enabled to access functionality that requires the full
-//! standard library.
+//! standard library, and the `alloc` feature can be enabled to access functionality that only requires an allocator.
+//! The `bytes` feature enables integration with the [`bytes`](https://docs.rs/bytes) crate, and the `testing` feature
+//! enables helpers for testing code at every possible misalignment.
 //! 
 //! [`UnalignedCell<T>`]: self::cell::UnalignedCell
 
@@ -27,7 +29,35 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod unaligned;
 pub mod cell;
+pub mod access;
+#[cfg(feature = "alloc")]
+pub mod arena;
+#[cfg(feature = "alloc")]
+pub mod archive;
+pub mod array_vec;
+#[cfg(feature = "bytes")]
+pub mod buf;
+pub mod cursor;
+pub mod endian;
+pub mod int;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod packed;
+pub mod packed_str;
+pub mod pod;
+pub mod ptr;
+pub mod rel;
+pub mod slice;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod tuple;
+#[cfg(feature = "alloc")]
+pub mod vec;
+mod bitfield;
 
 pub use self::unaligned::Unaligned;
@@ -0,0 +1,175 @@
+//! Partially aligned storage, for values whose alignment is guaranteed only up to a fixed number of bytes.
+//!
+//! Each type in this module stores a value of type `T` with an alignment of at most `N` bytes, like a
+//! `#[repr(packed(N))]` field. If the natural alignment of `T` is at most `N`, the value is always aligned, and the
+//! `get_aligned` methods always succeed. Otherwise, these types behave like [`Unaligned<T>`], to which they can be
+//! converted losslessly.
+//!
+//! ## Example
+//! ```
+//! # use unaligned::packed::Packed4;
+//! let mut value = Packed4::new(1u32);
+//! assert!(value.get_aligned().is_some());
+//! assert_eq!(4, core::mem::align_of::<Packed4<u64>>());
+//! value.with_mut(|v| *v += 1);
+//! assert_eq!(2, value.as_unaligned().get());
+//! ```
+
+use core::{
+    fmt::Debug,
+    mem::{self, ManuallyDrop},
+    ptr,
+};
+
+use crate::Unaligned;
+
+macro_rules! packed {
+    ($($name:ident($align:literal)),* $(,)?) => {
+        $(
+            #[doc = concat!("A value of type `T` with an alignment of at most ", stringify!($align), " bytes.")]
+            /// See the [module documentation](self) for more details.
+            #[repr(C, packed($align))]
+            #[derive(Default)]
+            pub struct $name<T>(T);
+
+            impl<T> $name<T> {
+                #[doc = concat!("Construct a new `", stringify!($name), "` with the given value.")]
+                pub const fn new(value: T) -> Self {
+                    Self(value)
+                }
+
+                #[doc = concat!("Consume this `", stringify!($name), "` and return the inner value.")]
+                pub fn into_inner(self) -> T {
+                    self.0
+                }
+
+                /// Get a read-only pointer to the inner value. See [`Unaligned::as_ptr`] for details.
+                pub const fn as_ptr(&self) -> *const T {
+                    ptr::addr_of!(self.0)
+                }
+
+                /// Get a writable pointer to the inner value. See [`Unaligned::as_mut_ptr`] for details.
+                pub fn as_mut_ptr(&mut self) -> *mut T {
+                    ptr::addr_of_mut!(self.0)
+                }
+
+                /// Get a shared reference to the inner value, if it is aligned. If the alignment of `T` is at most
+                #[doc = concat!(stringify!($align), " bytes, this method always returns `Some`.")]
+                pub fn get_aligned(&self) -> Option<&T> {
+                    let data_ptr = self.as_ptr();
+                    if mem::align_of::<T>() <= $align
+                        || data_ptr as usize % mem::align_of::<T>() == 0
+                    {
+                        // SAFETY: The data pointer is aligned, either by construction or by the check above.
+                        Some(unsafe { &*data_ptr })
+                    } else {
+                        None
+                    }
+                }
+
+                /// Get a mutable reference to the inner value, if it is aligned. If the alignment of `T` is at most
+                #[doc = concat!(stringify!($align), " bytes, this method always returns `Some`.")]
+                pub fn get_aligned_mut(&mut self) -> Option<&mut T> {
+                    let data_ptr = self.as_mut_ptr();
+                    if mem::align_of::<T>() <= $align
+                        || data_ptr as usize % mem::align_of::<T>() == 0
+                    {
+                        // SAFETY: The data pointer is aligned, either by construction or by the check above.
+                        Some(unsafe { &mut *data_ptr })
+                    } else {
+                        None
+                    }
+                }
+
+                #[doc = concat!("Swap the inner value of this `", stringify!($name), "` with another value.")]
+                pub fn swap(&mut self, other: &mut T) {
+                    self.with_mut(|val| mem::swap(val, other));
+                }
+
+                #[doc = concat!("Swap the inner value of this `", stringify!($name), "` with the given value, and return the former inner value.")]
+                pub fn replace(&mut self, value: T) -> T {
+                    self.with_mut(|val| mem::replace(val, value))
+                }
+
+                #[doc = concat!("Set the inner value of this `", stringify!($name), "`.")]
+                pub fn set(&mut self, value: T) {
+                    self.0 = value;
+                }
+
+                /// Mutably borrow the inner value and perform some computation with it. If the inner value is
+                /// aligned, it is borrowed in place. See [`Unaligned::with_mut`] for details.
+                pub fn with_mut<R, F>(&mut self, f: F) -> R
+                where
+                    F: FnOnce(&mut T) -> R,
+                {
+                    if let Some(val) = self.get_aligned_mut() {
+                        return f(val);
+                    }
+                    self.as_unaligned_mut().with_mut(f)
+                }
+
+                /// View this value as an [`Unaligned<T>`]. This conversion is free.
+                pub const fn as_unaligned(&self) -> &Unaligned<T> {
+                    // SAFETY: Both types have the same size as T, and Unaligned<T> has a weaker alignment.
+                    unsafe { &*(self as *const Self).cast() }
+                }
+
+                /// View this value as a mutable [`Unaligned<T>`]. This conversion is free.
+                pub fn as_unaligned_mut(&mut self) -> &mut Unaligned<T> {
+                    // SAFETY: Both types have the same size as T, and Unaligned<T> has a weaker alignment. Any value
+                    // written through the returned reference is a valid T.
+                    unsafe { &mut *(self as *mut Self).cast() }
+                }
+
+                /// Convert this value into an [`Unaligned<T>`].
+                pub fn into_unaligned(self) -> Unaligned<T> {
+                    let this = ManuallyDrop::new(self);
+                    // SAFETY: The value is moved out exactly once, and is not dropped here.
+                    unsafe { ptr::read(this.as_unaligned()) }
+                }
+            }
+
+            impl<T: Default> $name<T> {
+                #[doc = concat!("Replace the inner value of this `", stringify!($name), "` with the default value of type `T`, and return the former inner value.")]
+                pub fn take(&mut self) -> T {
+                    self.replace(T::default())
+                }
+            }
+
+            impl<T: Copy> $name<T> {
+                #[doc = concat!("Copy the inner value of this `", stringify!($name), "`.")]
+                pub const fn get(&self) -> T {
+                    self.0
+                }
+            }
+
+            impl<T> From<T> for $name<T> {
+                fn from(value: T) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl<T> From<$name<T>> for Unaligned<T> {
+                fn from(value: $name<T>) -> Self {
+                    value.into_unaligned()
+                }
+            }
+
+            impl<T: Copy> Clone for $name<T> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<T: Copy> Copy for $name<T> {}
+
+            impl<T> Debug for $name<T> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&"<unaligned>").finish()
+                }
+            }
+        )*
+    };
+}
+
+packed!(Packed2(2), Packed4(4), Packed8(8), Packed16(16));
@@ -0,0 +1,252 @@
+//! Fixed-length string fields, padded to their full length.
+//!
+//! Binary records often store names and labels as `char[N]` fields, padded with NUL bytes or spaces. A
+//! [`PackedStr`] stores such a field as an [`Unaligned<[u8; N]>`](Unaligned), and trims the padding when it is read.
+//!
+//! ## Example
+//! ```
+//! # use unaligned::packed_str::{PackedStr, SpacePadded};
+//! let mut name = PackedStr::<8>::new("unit").unwrap();
+//! assert_eq!(*b"unit\0\0\0\0", name.to_bytes());
+//! assert_eq!(Ok("unit"), name.as_str());
+//! assert!(name.set("too long!").is_err());
+//!
+//! let label = PackedStr::<6, SpacePadded>::from_bytes(*b"ab    ");
+//! assert_eq!("ab", label);
+//!
+//! // only spaces are padding
+//! let tabbed = PackedStr::<6, SpacePadded>::new("ab\t").unwrap();
+//! assert_eq!("ab\t", tabbed);
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Write},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::{self, Utf8Error},
+};
+
+use crate::Unaligned;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The padding scheme of a [`PackedStr`]. This trait is sealed, and is implemented only by [`NulPadded`] and
+/// [`SpacePadded`].
+pub trait Padding: sealed::Sealed + Copy + Default + Debug + Send + Sync + 'static {
+    /// The byte used to pad the unused part of a field.
+    const BYTE: u8;
+
+    /// Remove the padding from the given field contents.
+    fn trim(bytes: &[u8]) -> &[u8];
+}
+
+/// Padding with NUL bytes. The contents of a field end at its first NUL byte, if any.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NulPadded;
+
+/// Padding with spaces. Trailing spaces are not part of the contents of a field.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpacePadded;
+
+impl sealed::Sealed for NulPadded {}
+
+impl Padding for NulPadded {
+    const BYTE: u8 = 0;
+
+    fn trim(bytes: &[u8]) -> &[u8] {
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        &bytes[..len]
+    }
+}
+
+impl sealed::Sealed for SpacePadded {}
+
+impl Padding for SpacePadded {
+    const BYTE: u8 = b' ';
+
+    fn trim(bytes: &[u8]) -> &[u8] {
+        let len = bytes.iter().rposition(|&b| b != b' ').map_or(0, |i| i + 1);
+        &bytes[..len]
+    }
+}
+
+/// The error returned when a string is too long for a [`PackedStr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooLong;
+
+#[cfg(feature = "std")]
+impl std::error::Error for TooLong {}
+
+impl Display for TooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("string too long for field")
+    }
+}
+
+/// A string field of exactly `N` bytes, padded according to `P`. Comparisons and hashing use the contents of the
+/// field with the padding removed.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct PackedStr<const N: usize, P: Padding = NulPadded> {
+    bytes: Unaligned<[u8; N]>,
+    padding: PhantomData<P>,
+}
+
+impl<const N: usize, P: Padding> PackedStr<N, P> {
+    /// Construct an empty field, which is filled with padding.
+    pub const fn empty() -> Self {
+        Self::from_bytes([P::BYTE; N])
+    }
+
+    /// Construct a field containing the given string. If the string is longer than `N` bytes, this function
+    /// returns an error.
+    pub fn new(s: &str) -> Result<Self, TooLong> {
+        let mut field = Self::empty();
+        field.set(s)?;
+        Ok(field)
+    }
+
+    /// Construct a field from its raw bytes, including any padding.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        Self {
+            bytes: Unaligned::new(bytes),
+            padding: PhantomData,
+        }
+    }
+
+    /// Get the raw bytes of this field, including any padding.
+    pub const fn to_bytes(&self) -> [u8; N] {
+        self.bytes.get()
+    }
+
+    /// Get the contents of this field with the padding removed.
+    pub fn as_bytes(&self) -> &[u8] {
+        P::trim(self.bytes.as_bytes())
+    }
+
+    /// Get the contents of this field with the padding removed, as a string. If the contents are not valid UTF-8,
+    /// this method returns an error.
+    pub fn as_str(&self) -> Result<&str, Utf8Error> {
+        str::from_utf8(self.as_bytes())
+    }
+
+    /// Get the length in bytes of the contents of this field.
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Return `true` if this field has no contents.
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+
+    /// Set the contents of this field to the given string, padding the rest of the field. If the string is longer
+    /// than `N` bytes, this method returns an error and the field is unchanged.
+    ///
+    /// Note that padding bytes at the end of the string (or any NUL byte, for [`NulPadded`] fields) are
+    /// indistinguishable from padding, and will not be part of the contents when the field is read.
+    pub fn set(&mut self, s: &str) -> Result<(), TooLong> {
+        if s.len() > N {
+            return Err(TooLong);
+        }
+        let mut bytes = [P::BYTE; N];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        self.bytes.set(bytes);
+        Ok(())
+    }
+}
+
+// trait implementations
+
+impl<const N: usize, P: Padding> Default for PackedStr<N, P> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<const N: usize, P: Padding> TryFrom<&str> for PackedStr<N, P> {
+    type Error = TooLong;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize, P: Padding> PartialEq for PackedStr<N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, P: Padding> Eq for PackedStr<N, P> {}
+
+impl<const N: usize, P: Padding> PartialEq<str> for PackedStr<N, P> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, P: Padding> PartialEq<&str> for PackedStr<N, P> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, P: Padding> PartialEq<PackedStr<N, P>> for str {
+    fn eq(&self, other: &PackedStr<N, P>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, P: Padding> PartialEq<PackedStr<N, P>> for &str {
+    fn eq(&self, other: &PackedStr<N, P>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize, P: Padding> PartialOrd for PackedStr<N, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, P: Padding> Ord for PackedStr<N, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<const N: usize, P: Padding> Hash for PackedStr<N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+/// Formats the contents of the field as a string, or as bytes if they are not valid UTF-8.
+impl<const N: usize, P: Padding> Debug for PackedStr<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Ok(s) => Debug::fmt(s, f),
+            Err(_) => Debug::fmt(self.as_bytes(), f),
+        }
+    }
+}
+
+/// Formats the contents of the field, replacing invalid UTF-8 with `U+FFFD REPLACEMENT CHARACTER`.
+impl<const N: usize, P: Padding> Display for PackedStr<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Ok(s) = self.as_str() {
+            return Display::fmt(s, f);
+        }
+        for chunk in self.as_bytes().utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_char(char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
+    }
+}
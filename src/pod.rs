@@ -0,0 +1,92 @@
+//! Plain data types, which can be freely converted to and from bytes.
+
+use core::marker::PhantomData;
+
+use crate::{
+    endian::ByteOrder,
+    int::{I24, I40, I48, I56, U24, U40, U48, U56},
+    packed::{Packed16, Packed2, Packed4, Packed8},
+    packed_str::{PackedStr, Padding},
+    tuple::{
+        PackedTuple1, PackedTuple2, PackedTuple3, PackedTuple4, PackedTuple5, PackedTuple6,
+        PackedTuple7, PackedTuple8,
+    },
+    Unaligned,
+};
+
+/// A plain data type, for which every byte sequence of the correct length is a valid value.
+///
+/// ## Safety
+/// Implementors must ensure that the type has the following properties.
+/// - Every bit pattern of the size of the type is a valid value of the type.
+/// - The type contains no padding bytes, so every byte of every value is initialized.
+/// - The type contains no interior mutability.
+///
+/// Note that `#[repr(C)]` structs whose fields are all `Pod` may still contain padding between fields. Structs made
+/// entirely of [`Unaligned`] fields (or other types with alignment 1) never contain padding.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            // SAFETY: Primitive numeric types have no padding, no interior mutability, and no invalid bit patterns.
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// SAFETY: Arrays have no padding between elements, and inherit the other properties from their elements.
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+// SAFETY: Unaligned<T> has the same size and validity as T, and has no padding of its own.
+unsafe impl<T: Pod> Pod for Unaligned<T> {}
+
+// SAFETY: PhantomData is a zero-sized type with a single value.
+unsafe impl<T: ?Sized + 'static> Pod for PhantomData<T> {}
+
+macro_rules! impl_pod_odd_int {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            // SAFETY: The odd-width integers consist only of a byte array, so every bit pattern is valid.
+            unsafe impl<E: ByteOrder> Pod for $ty<E> {}
+        )*
+    };
+}
+
+impl_pod_odd_int!(U24, I24, U40, I40, U48, I48, U56, I56);
+
+macro_rules! impl_pod_packed {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            // SAFETY: The packed wrappers have the same size and validity as T, and have no padding of their own.
+            unsafe impl<T: Pod> Pod for $ty<T> {}
+        )*
+    };
+}
+
+impl_pod_packed!(Packed2, Packed4, Packed8, Packed16);
+
+// SAFETY: A PackedStr consists only of a byte array, and its contents are validated when they are read.
+unsafe impl<const N: usize, P: Padding> Pod for PackedStr<N, P> {}
+
+macro_rules! impl_pod_packed_tuple {
+    ($($ty:ident<$($param:ident),+>),* $(,)?) => {
+        $(
+            // SAFETY: Packed tuples have no padding between fields, and inherit the other properties from their fields.
+            unsafe impl<$($param: Pod),+> Pod for $ty<$($param),+> {}
+        )*
+    };
+}
+
+impl_pod_packed_tuple!(
+    PackedTuple1<A>,
+    PackedTuple2<A, B>,
+    PackedTuple3<A, B, C>,
+    PackedTuple4<A, B, C, D>,
+    PackedTuple5<A, B, C, D, E>,
+    PackedTuple6<A, B, C, D, E, F>,
+    PackedTuple7<A, B, C, D, E, F, G>,
+    PackedTuple8<A, B, C, D, E, F, G, H>,
+);
@@ -0,0 +1,491 @@
+//! Raw pointers that always perform unaligned reads and writes.
+
+use core::{
+    fmt::{self, Debug, Pointer},
+    hash::{Hash, Hasher},
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ptr::{self, NonNull},
+};
+
+use crate::Unaligned;
+
+/// A raw pointer to a possibly unaligned value of type `T`.
+///
+/// Unlike `*mut T`, every read and write through this pointer is unaligned, so it is never necessary to check the
+/// alignment of the address. Offsets are measured in units of `T`, just like `*mut T`.
+///
+/// ## Example
+/// ```
+/// # use unaligned::ptr::UnalignedPtr;
+/// let mut bytes = [0u8; 9];
+/// let ptr = UnalignedPtr::new(bytes.as_mut_ptr().wrapping_add(1)).cast::<u32>();
+/// unsafe {
+///     ptr.write(u32::from_ne_bytes([1, 2, 3, 4]));
+///     ptr.add(1).write(u32::from_ne_bytes([5, 6, 7, 8]));
+///     assert_eq!([5, 6, 7, 8], ptr.add(1).read().to_ne_bytes());
+/// }
+/// assert_eq!([0, 1, 2, 3, 4, 5, 6, 7, 8], bytes);
+/// ```
+pub struct UnalignedPtr<T> {
+    ptr: *mut Unaligned<T>,
+}
+
+impl<T> UnalignedPtr<T> {
+    /// Construct a new pointer from the given raw pointer, which need not be aligned.
+    pub const fn new(ptr: *mut T) -> Self {
+        Self { ptr: ptr.cast() }
+    }
+
+    /// Construct a null pointer.
+    pub const fn null() -> Self {
+        Self::new(ptr::null_mut())
+    }
+
+    /// Return `true` if this pointer is null.
+    pub fn is_null(self) -> bool {
+        self.ptr.is_null()
+    }
+
+    /// Get the address of this pointer as a raw pointer to `T`.
+    ///
+    /// **Caution:** The returned pointer is almost certainly unaligned. You should only perform operations that are
+    /// safe with unaligned pointers.
+    pub const fn as_ptr(self) -> *mut T {
+        self.ptr.cast()
+    }
+
+    /// Get the address of this pointer as a raw pointer to [`Unaligned<T>`], which is always aligned.
+    pub const fn as_unaligned_ptr(self) -> *mut Unaligned<T> {
+        self.ptr
+    }
+
+    /// Cast this pointer to a pointer to a value of another type.
+    pub const fn cast<U>(self) -> UnalignedPtr<U> {
+        UnalignedPtr {
+            ptr: self.ptr.cast(),
+        }
+    }
+
+    /// Calculate the offset of this pointer by `count` values of type `T`.
+    ///
+    /// ## Safety
+    /// The same rules as [`pointer::add`][add] apply. In particular, the resulting pointer must be in bounds of the same
+    /// allocation as this pointer.
+    ///
+    /// [add]: https://doc.rust-lang.org/core/primitive.pointer.html#method.add
+    pub const unsafe fn add(self, count: usize) -> Self {
+        // SAFETY: The caller upholds the safety requirements of add.
+        Self {
+            ptr: unsafe { self.ptr.add(count) },
+        }
+    }
+
+    /// Calculate the offset of this pointer by `count` values of type `T`, which may be negative.
+    ///
+    /// ## Safety
+    /// The same rules as [`pointer::offset`][offset] apply. In particular, the resulting pointer must be in bounds of the
+    /// same allocation as this pointer.
+    ///
+    /// [offset]: https://doc.rust-lang.org/core/primitive.pointer.html#method.offset
+    pub const unsafe fn offset(self, count: isize) -> Self {
+        // SAFETY: The caller upholds the safety requirements of offset.
+        Self {
+            ptr: unsafe { self.ptr.offset(count) },
+        }
+    }
+
+    /// Calculate the offset of this pointer by `count` values of type `T`, using wrapping arithmetic. The resulting
+    /// pointer may only be dereferenced if it is in bounds of the same allocation as this pointer.
+    pub const fn wrapping_add(self, count: usize) -> Self {
+        Self {
+            ptr: self.ptr.wrapping_add(count),
+        }
+    }
+
+    /// Calculate the offset of this pointer by `count` values of type `T`, which may be negative, using wrapping
+    /// arithmetic. The resulting pointer may only be dereferenced if it is in bounds of the same allocation as this
+    /// pointer.
+    pub const fn wrapping_offset(self, count: isize) -> Self {
+        Self {
+            ptr: self.ptr.wrapping_offset(count),
+        }
+    }
+
+    /// Read the value from this pointer without moving it. See [`ptr::read_unaligned`] for details.
+    ///
+    /// ## Safety
+    /// The pointer must be valid for unaligned reads at type `T`, and must point to a valid value of type `T`. If `T`
+    /// is not `Copy`, the caller must ensure that the value is not used or dropped twice.
+    pub unsafe fn read(self) -> T {
+        // SAFETY: The caller has ensured that the pointer is valid for reads, and Unaligned<T> has alignment 1.
+        unsafe { self.ptr.read() }.into_inner()
+    }
+
+    /// Write the given value to this pointer without dropping the old value. See [`ptr::write_unaligned`] for details.
+    ///
+    /// ## Safety
+    /// The pointer must be valid for unaligned writes at type `T`.
+    pub unsafe fn write(self, value: T) {
+        // SAFETY: The caller has ensured that the pointer is valid for writes, and Unaligned<T> has alignment 1.
+        unsafe { self.ptr.write(Unaligned::new(value)) };
+    }
+
+    /// Replace the value at this pointer with the given value, returning the old value. Neither value is dropped.
+    ///
+    /// ## Safety
+    /// The pointer must be valid for unaligned reads and writes at type `T`, and must point to a valid value of type
+    /// `T`.
+    pub unsafe fn replace(self, value: T) -> T {
+        // SAFETY: The caller has ensured that the pointer is valid for reads and writes, and Unaligned<T> has
+        // alignment 1.
+        unsafe { ptr::replace(self.ptr, Unaligned::new(value)) }.into_inner()
+    }
+
+    /// Swap the values at two pointers, which may overlap. See [`ptr::swap`] for details.
+    ///
+    /// ## Safety
+    /// Both pointers must be valid for unaligned reads and writes at type `T`.
+    pub unsafe fn swap(self, other: Self) {
+        // SAFETY: The caller has ensured that both pointers are valid for reads and writes, and Unaligned<T> has
+        // alignment 1.
+        unsafe { ptr::swap(self.ptr, other.ptr) };
+    }
+
+    /// Read the value from this pointer with volatile accesses, without moving it.
+    ///
+    /// Each byte of the value is read with a separate volatile access, in order of increasing address. This is
+    /// permissible at any alignment, which makes it suitable for memory-mapped registers at odd offsets, as long as
+    /// the device allows byte-sized accesses. The read as a whole is neither atomic nor tear-free.
+    ///
+    /// ## Safety
+    /// The same rules as [`UnalignedPtr::read`] apply.
+    pub unsafe fn read_volatile(self) -> T {
+        let mut value = MaybeUninit::<T>::uninit();
+        let src = self.ptr.cast::<MaybeUninit<u8>>();
+        let dst = value.as_mut_ptr().cast::<MaybeUninit<u8>>();
+        for i in 0..mem::size_of::<T>() {
+            // SAFETY: The caller has ensured that the pointer is valid for reads of the whole value, and the
+            // destination is a local value of the same size. Bytes have alignment 1.
+            unsafe { dst.add(i).write(src.add(i).read_volatile()) };
+        }
+        // SAFETY: Every byte was copied from a valid value of type T.
+        unsafe { value.assume_init() }
+    }
+
+    /// Write the given value to this pointer with volatile accesses, without dropping the old value.
+    ///
+    /// Each byte of the value is written with a separate volatile access, in order of increasing address. See
+    /// [`UnalignedPtr::read_volatile`] for details.
+    ///
+    /// ## Safety
+    /// The same rules as [`UnalignedPtr::write`] apply.
+    pub unsafe fn write_volatile(self, value: T) {
+        let value = ManuallyDrop::new(value);
+        let src = (&*value as *const T).cast::<MaybeUninit<u8>>();
+        let dst = self.ptr.cast::<MaybeUninit<u8>>();
+        for i in 0..mem::size_of::<T>() {
+            // SAFETY: The caller has ensured that the pointer is valid for writes of the whole value, and the source
+            // is a local value of the same size. Bytes have alignment 1.
+            unsafe { dst.add(i).write_volatile(src.add(i).read()) };
+        }
+    }
+
+    /// Convert this pointer to a shared reference, or return `None` if it is null.
+    ///
+    /// ## Safety
+    /// If the pointer is not null, the same rules as [`Unaligned::from_ptr`] apply.
+    pub unsafe fn as_ref<'a>(self) -> Option<&'a Unaligned<T>> {
+        // SAFETY: The caller upholds the safety requirements of from_ptr.
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// Convert this pointer to a mutable reference, or return `None` if it is null.
+    ///
+    /// ## Safety
+    /// If the pointer is not null, the same rules as [`Unaligned::from_mut_ptr`] apply.
+    pub unsafe fn as_mut<'a>(self) -> Option<&'a mut Unaligned<T>> {
+        // SAFETY: The caller upholds the safety requirements of from_mut_ptr.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+/// A non-null raw pointer to a possibly unaligned value of type `T`. This is the non-null counterpart to
+/// [`UnalignedPtr`], and is covariant in `T` like [`NonNull`].
+///
+/// ## Example
+/// ```
+/// # use unaligned::{ptr::UnalignedNonNull, Unaligned};
+/// let mut values = [Unaligned::new(1u64), Unaligned::new(2)];
+/// let first = UnalignedNonNull::new(values.as_mut_ptr().cast::<u64>()).unwrap();
+/// unsafe {
+///     first.swap(first.add(1));
+///     assert_eq!(1, first.add(1).replace(3));
+/// }
+/// assert_eq!([2, 3], values.map(Unaligned::into_inner));
+/// ```
+pub struct UnalignedNonNull<T> {
+    ptr: NonNull<Unaligned<T>>,
+}
+
+impl<T> UnalignedNonNull<T> {
+    /// Construct a new pointer from the given raw pointer, or return `None` if it is null.
+    pub fn new(ptr: *mut T) -> Option<Self> {
+        NonNull::new(ptr.cast()).map(|ptr| Self { ptr })
+    }
+
+    /// Construct a new pointer from the given raw pointer without checking if it is null.
+    ///
+    /// ## Safety
+    /// The pointer must not be null.
+    pub const unsafe fn new_unchecked(ptr: *mut T) -> Self {
+        // SAFETY: The caller has ensured that the pointer is not null.
+        Self {
+            ptr: unsafe { NonNull::new_unchecked(ptr.cast()) },
+        }
+    }
+
+    /// Construct a dangling pointer, which is valid for zero-sized reads and writes.
+    pub const fn dangling() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+        }
+    }
+
+    /// Get the address of this pointer as a raw pointer to `T`.
+    ///
+    /// **Caution:** The returned pointer is almost certainly unaligned. You should only perform operations that are
+    /// safe with unaligned pointers.
+    pub const fn as_ptr(self) -> *mut T {
+        self.ptr.as_ptr().cast()
+    }
+
+    /// Get the address of this pointer as a non-null pointer to [`Unaligned<T>`], which is always aligned.
+    pub const fn as_non_null(self) -> NonNull<Unaligned<T>> {
+        self.ptr
+    }
+
+    /// Convert this pointer to a possibly null [`UnalignedPtr`].
+    pub const fn as_unaligned_ptr(self) -> UnalignedPtr<T> {
+        UnalignedPtr {
+            ptr: self.ptr.as_ptr(),
+        }
+    }
+
+    /// Cast this pointer to a pointer to a value of another type.
+    pub const fn cast<U>(self) -> UnalignedNonNull<U> {
+        UnalignedNonNull {
+            ptr: self.ptr.cast(),
+        }
+    }
+
+    /// Calculate the offset of this pointer by `count` values of type `T`.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::add`].
+    pub const unsafe fn add(self, count: usize) -> Self {
+        // SAFETY: The caller upholds the safety requirements of add, and an in-bounds offset is never null.
+        Self {
+            ptr: unsafe { self.ptr.add(count) },
+        }
+    }
+
+    /// Calculate the offset of this pointer by `count` values of type `T`, which may be negative.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::offset`].
+    pub const unsafe fn offset(self, count: isize) -> Self {
+        // SAFETY: The caller upholds the safety requirements of offset, and an in-bounds offset is never null.
+        Self {
+            ptr: unsafe { self.ptr.offset(count) },
+        }
+    }
+
+    /// Read the value from this pointer without moving it.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::read`].
+    pub unsafe fn read(self) -> T {
+        // SAFETY: The caller upholds the safety requirements of read.
+        unsafe { self.as_unaligned_ptr().read() }
+    }
+
+    /// Write the given value to this pointer without dropping the old value.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::write`].
+    pub unsafe fn write(self, value: T) {
+        // SAFETY: The caller upholds the safety requirements of write.
+        unsafe { self.as_unaligned_ptr().write(value) };
+    }
+
+    /// Replace the value at this pointer with the given value, returning the old value.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::replace`].
+    pub unsafe fn replace(self, value: T) -> T {
+        // SAFETY: The caller upholds the safety requirements of replace.
+        unsafe { self.as_unaligned_ptr().replace(value) }
+    }
+
+    /// Swap the values at two pointers, which may overlap.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::swap`].
+    pub unsafe fn swap(self, other: Self) {
+        // SAFETY: The caller upholds the safety requirements of swap.
+        unsafe { self.as_unaligned_ptr().swap(other.as_unaligned_ptr()) };
+    }
+
+    /// Read the value from this pointer with volatile accesses, without moving it.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::read_volatile`].
+    pub unsafe fn read_volatile(self) -> T {
+        // SAFETY: The caller upholds the safety requirements of read_volatile.
+        unsafe { self.as_unaligned_ptr().read_volatile() }
+    }
+
+    /// Write the given value to this pointer with volatile accesses, without dropping the old value.
+    ///
+    /// ## Safety
+    /// See [`UnalignedPtr::write_volatile`].
+    pub unsafe fn write_volatile(self, value: T) {
+        // SAFETY: The caller upholds the safety requirements of write_volatile.
+        unsafe { self.as_unaligned_ptr().write_volatile(value) };
+    }
+
+    /// Convert this pointer to a shared reference.
+    ///
+    /// ## Safety
+    /// The same rules as [`Unaligned::from_ptr`] apply.
+    pub unsafe fn as_ref<'a>(self) -> &'a Unaligned<T> {
+        // SAFETY: The caller upholds the safety requirements of from_ptr.
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// Convert this pointer to a mutable reference.
+    ///
+    /// ## Safety
+    /// The same rules as [`Unaligned::from_mut_ptr`] apply.
+    pub unsafe fn as_mut<'a>(mut self) -> &'a mut Unaligned<T> {
+        // SAFETY: The caller upholds the safety requirements of from_mut_ptr.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+// trait implementations
+
+macro_rules! impl_ptr_traits {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<T> Clone for $name<T> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<T> Copy for $name<T> {}
+
+            impl<T> PartialEq for $name<T> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.ptr == other.ptr
+                }
+            }
+
+            impl<T> Eq for $name<T> {}
+
+            impl<T> Hash for $name<T> {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.ptr.hash(state);
+                }
+            }
+
+            impl<T> Debug for $name<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    Pointer::fmt(&self.as_ptr(), f)
+                }
+            }
+
+            impl<T> Pointer for $name<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    Pointer::fmt(&self.as_ptr(), f)
+                }
+            }
+
+        )*
+    };
+}
+
+impl_ptr_traits!(UnalignedPtr, UnalignedNonNull);
+
+impl<T> From<*mut Unaligned<T>> for UnalignedPtr<T> {
+    fn from(ptr: *mut Unaligned<T>) -> Self {
+        Self { ptr }
+    }
+}
+
+impl<T> From<*const Unaligned<T>> for UnalignedPtr<T> {
+    fn from(ptr: *const Unaligned<T>) -> Self {
+        Self {
+            ptr: ptr.cast_mut(),
+        }
+    }
+}
+
+impl<T> From<UnalignedPtr<T>> for *mut Unaligned<T> {
+    fn from(ptr: UnalignedPtr<T>) -> Self {
+        ptr.ptr
+    }
+}
+
+impl<T> From<UnalignedPtr<T>> for *const Unaligned<T> {
+    fn from(ptr: UnalignedPtr<T>) -> Self {
+        ptr.ptr
+    }
+}
+
+impl<T> From<&Unaligned<T>> for UnalignedPtr<T> {
+    fn from(value: &Unaligned<T>) -> Self {
+        Self::from(value as *const Unaligned<T>)
+    }
+}
+
+impl<T> From<&mut Unaligned<T>> for UnalignedPtr<T> {
+    fn from(value: &mut Unaligned<T>) -> Self {
+        Self::from(value as *mut Unaligned<T>)
+    }
+}
+
+impl<T> From<UnalignedNonNull<T>> for UnalignedPtr<T> {
+    fn from(ptr: UnalignedNonNull<T>) -> Self {
+        ptr.as_unaligned_ptr()
+    }
+}
+
+impl<T> From<NonNull<Unaligned<T>>> for UnalignedNonNull<T> {
+    fn from(ptr: NonNull<Unaligned<T>>) -> Self {
+        Self { ptr }
+    }
+}
+
+impl<T> From<UnalignedNonNull<T>> for NonNull<Unaligned<T>> {
+    fn from(ptr: UnalignedNonNull<T>) -> Self {
+        ptr.ptr
+    }
+}
+
+impl<T> From<&Unaligned<T>> for UnalignedNonNull<T> {
+    fn from(value: &Unaligned<T>) -> Self {
+        Self {
+            ptr: NonNull::from(value),
+        }
+    }
+}
+
+impl<T> From<&mut Unaligned<T>> for UnalignedNonNull<T> {
+    fn from(value: &mut Unaligned<T>) -> Self {
+        Self {
+            ptr: NonNull::from(value),
+        }
+    }
+}
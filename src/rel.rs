@@ -0,0 +1,265 @@
+//! Position-independent relative pointers, stored unaligned.
+//!
+//! A relative pointer stores the signed offset from its own address to its target, so a data structure built from
+//! relative pointers remains valid when it is moved or mapped at a different address, as long as its targets move
+//! with it. An offset of zero represents a null pointer.
+
+use core::{
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+    slice,
+};
+
+use crate::Unaligned;
+
+/// The error returned when the target of a relative pointer is too far away, or is the pointer itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for OffsetError {}
+
+impl Display for OffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("relative offset out of range")
+    }
+}
+
+/// A relative pointer to an unaligned value of type `T`, stored as an unaligned `i32` offset from its own address.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{rel::RelPtr, Unaligned};
+/// #[repr(C)]
+/// struct Record {
+///     value: Unaligned<u64>,
+///     ptr: RelPtr<u64>,
+/// }
+///
+/// let mut record = Record { value: Unaligned::new(42), ptr: RelPtr::null() };
+/// record.ptr.set(&record.value).unwrap();
+///
+/// // the relative pointer remains valid when the record is moved
+/// let moved = Box::new(record);
+/// assert_eq!(42, unsafe { moved.ptr.get() }.get());
+/// ```
+#[repr(transparent)]
+pub struct RelPtr<T> {
+    offset: Unaligned<i32>,
+    marker: PhantomData<*const T>,
+}
+
+impl<T> RelPtr<T> {
+    /// Construct a null relative pointer.
+    pub const fn null() -> Self {
+        Self::from_offset(0)
+    }
+
+    /// Construct a relative pointer with the given offset in bytes. An offset of zero represents a null pointer.
+    pub const fn from_offset(offset: i32) -> Self {
+        Self {
+            offset: Unaligned::new(offset),
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the offset in bytes from this pointer to its target.
+    pub const fn offset(&self) -> i32 {
+        self.offset.get()
+    }
+
+    /// Return `true` if this pointer is null.
+    pub const fn is_null(&self) -> bool {
+        self.offset() == 0
+    }
+
+    /// Get the absolute address of the target of this pointer, or a null pointer if this pointer is null.
+    pub fn as_ptr(&self) -> *const Unaligned<T> {
+        if self.is_null() {
+            return core::ptr::null();
+        }
+        (self as *const Self)
+            .cast::<u8>()
+            .wrapping_offset(self.offset() as isize)
+            .cast()
+    }
+
+    /// Get the absolute address of the target of this pointer for writing, or a null pointer if this pointer is
+    /// null.
+    pub fn as_mut_ptr(&mut self) -> *mut Unaligned<T> {
+        self.as_ptr().cast_mut()
+    }
+
+    /// Point this pointer at the given target. If the offset to the target does not fit in an `i32`, or the target
+    /// is this pointer itself, this method returns an error and the pointer is unchanged.
+    pub fn set(&mut self, target: *const Unaligned<T>) -> Result<(), OffsetError> {
+        let offset = (target as isize).wrapping_sub(self as *const Self as isize);
+        match i32::try_from(offset) {
+            Ok(offset) if offset != 0 => {
+                self.offset.set(offset);
+                Ok(())
+            }
+            _ => Err(OffsetError),
+        }
+    }
+
+    /// Make this pointer null.
+    pub fn set_null(&mut self) {
+        self.offset.set(0);
+    }
+
+    /// Get a shared reference to the target of this pointer.
+    ///
+    /// ## Safety
+    /// The pointer must not be null, and the same rules as [`Unaligned::from_ptr`] apply to its target.
+    pub unsafe fn get(&self) -> &Unaligned<T> {
+        // SAFETY: The caller upholds the safety requirements of from_ptr.
+        unsafe { Unaligned::from_ptr(self.as_ptr().cast()) }
+    }
+
+    /// Get a mutable reference to the target of this pointer.
+    ///
+    /// ## Safety
+    /// The pointer must not be null, and the same rules as [`Unaligned::from_mut_ptr`] apply to its target.
+    pub unsafe fn get_mut(&mut self) -> &mut Unaligned<T> {
+        // SAFETY: The caller upholds the safety requirements of from_mut_ptr.
+        unsafe { Unaligned::from_mut_ptr(self.as_mut_ptr().cast()) }
+    }
+}
+
+/// A relative pointer to a slice of unaligned values of type `T`. Its layout is an unaligned `i32` offset from its own
+/// address, followed by an unaligned `u32` length.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{rel::RelSlice, Unaligned};
+/// #[repr(C)]
+/// struct Table {
+///     values: [Unaligned<u16>; 3],
+///     slice: RelSlice<u16>,
+/// }
+///
+/// let mut table = Table { values: [1, 2, 3].map(Unaligned::new), slice: RelSlice::empty() };
+/// table.slice.set(&table.values[1..]).unwrap();
+/// let slice = unsafe { table.slice.get() };
+/// assert_eq!([2, 3], [slice[0].get(), slice[1].get()]);
+/// ```
+#[repr(C)]
+pub struct RelSlice<T> {
+    ptr: RelPtr<T>,
+    len: Unaligned<u32>,
+}
+
+impl<T> RelSlice<T> {
+    /// Construct a relative pointer to an empty slice.
+    pub const fn empty() -> Self {
+        Self::from_raw_parts(0, 0)
+    }
+
+    /// Construct a relative pointer to a slice with the given offset in bytes and length.
+    pub const fn from_raw_parts(offset: i32, len: u32) -> Self {
+        Self {
+            ptr: RelPtr::from_offset(offset),
+            len: Unaligned::new(len),
+        }
+    }
+
+    /// Get the offset in bytes from this pointer to its target.
+    pub const fn offset(&self) -> i32 {
+        self.ptr.offset()
+    }
+
+    /// Get the number of values in the target slice.
+    pub const fn len(&self) -> usize {
+        self.len.get() as usize
+    }
+
+    /// Return `true` if the target slice is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the absolute address of the first value of the target slice, or a null pointer if the offset is zero.
+    pub fn as_ptr(&self) -> *const Unaligned<T> {
+        self.ptr.as_ptr()
+    }
+
+    /// Get the absolute address of the first value of the target slice for writing, or a null pointer if the offset
+    /// is zero.
+    pub fn as_mut_ptr(&mut self) -> *mut Unaligned<T> {
+        self.ptr.as_mut_ptr()
+    }
+
+    /// Point this pointer at the given target slice. If the offset to the target does not fit in an `i32`, the
+    /// target is this pointer itself, or the length does not fit in a `u32`, this method returns an error and the
+    /// pointer is unchanged.
+    pub fn set(&mut self, target: *const [Unaligned<T>]) -> Result<(), OffsetError> {
+        let len = u32::try_from(target.len()).map_err(|_| OffsetError)?;
+        self.ptr.set(target.cast())?;
+        self.len.set(len);
+        Ok(())
+    }
+
+    /// Get a shared reference to the target slice. If the slice is empty, the offset is ignored.
+    ///
+    /// ## Safety
+    /// If the slice is not empty, the pointer must not be null, and must point to `len` valid values that are not
+    /// mutably borrowed for the lifetime of the returned reference.
+    pub unsafe fn get(&self) -> &[Unaligned<T>] {
+        if self.is_empty() {
+            return &[];
+        }
+        // SAFETY: The caller has ensured that the target is valid, and Unaligned<T> has alignment 1.
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    /// Get a mutable reference to the target slice. If the slice is empty, the offset is ignored.
+    ///
+    /// ## Safety
+    /// If the slice is not empty, the pointer must not be null, and must point to `len` valid values that are not
+    /// otherwise borrowed for the lifetime of the returned reference.
+    pub unsafe fn get_mut(&mut self) -> &mut [Unaligned<T>] {
+        if self.is_empty() {
+            return &mut [];
+        }
+        let len = self.len();
+        // SAFETY: The caller has ensured that the target is valid, and Unaligned<T> has alignment 1.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), len) }
+    }
+}
+
+// trait implementations
+
+// SAFETY: An owned relative pointer can give shared or mutable access to its target, like a shared or mutable
+// reference would.
+unsafe impl<T: Send + Sync> Send for RelPtr<T> {}
+
+// SAFETY: A shared relative pointer gives only shared access to its target, like a shared reference would.
+unsafe impl<T: Sync> Sync for RelPtr<T> {}
+
+impl<T> Default for RelPtr<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> Debug for RelPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RelPtr").field(&self.offset()).finish()
+    }
+}
+
+impl<T> Default for RelSlice<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Debug for RelSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelSlice")
+            .field("offset", &self.offset())
+            .field("len", &self.len())
+            .finish()
+    }
+}
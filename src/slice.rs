@@ -0,0 +1,337 @@
+//! Operations on slices of unaligned values.
+
+use core::{cmp::Ordering, iter::FusedIterator, slice};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Unaligned;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl<T> sealed::Sealed for [Unaligned<T>] {}
+
+/// Extension methods for slices of unaligned values. This trait is sealed, and is implemented only for `[Unaligned<T>]`.
+///
+/// Methods are suffixed with `_values` where the name would otherwise collide with an inherent slice method, because
+/// the inherent methods operate on `Unaligned<T>` rather than on `T`.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{slice::UnalignedSliceExt, Unaligned};
+/// let mut values = [3, 1, 2].map(Unaligned::new);
+/// values.sort_unstable_values();
+/// assert!(values.contains_value(&2));
+/// assert_eq!(Ok(2), values.binary_search_value(&3));
+///
+/// let mut aligned = [0; 3];
+/// values.copy_to_aligned(&mut aligned);
+/// assert_eq!([1, 2, 3], aligned);
+/// ```
+pub trait UnalignedSliceExt<T>: sealed::Sealed {
+    /// Iterate over copies of the values in this slice.
+    fn iter_copied(&self) -> IterCopied<'_, T>
+    where
+        T: Copy;
+
+    /// Return `true` if this slice contains a value equal to the given value.
+    fn contains_value(&self, value: &T) -> bool
+    where
+        T: Copy + PartialEq;
+
+    /// Binary search this sorted slice for the given value. See [`slice::binary_search`] for details.
+    fn binary_search_value(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Copy + Ord;
+
+    /// Binary search this sorted slice with a comparator function. See [`slice::binary_search_by`] for details.
+    fn binary_search_value_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        T: Copy,
+        F: FnMut(&T) -> Ordering;
+
+    /// Fill this slice with clones of the given value. The previous values are dropped.
+    fn fill_value(&mut self, value: T)
+    where
+        T: Clone;
+
+    /// Swap the values at the two given indices.
+    ///
+    /// ## Panics
+    /// This method panics if either index is out of bounds.
+    fn swap_values(&mut self, a: usize, b: usize);
+
+    /// Copy all values from an aligned slice into this slice.
+    ///
+    /// ## Panics
+    /// This method panics if the two slices have different lengths.
+    fn copy_from_aligned(&mut self, src: &[T])
+    where
+        T: Copy;
+
+    /// Copy all values from this slice into an aligned slice.
+    ///
+    /// ## Panics
+    /// This method panics if the two slices have different lengths.
+    fn copy_to_aligned(&self, dst: &mut [T])
+    where
+        T: Copy;
+
+    /// Sort this slice without preserving the order of equal values. This sort is in-place and does not allocate.
+    fn sort_unstable_values(&mut self)
+    where
+        T: Ord;
+
+    /// Sort this slice with a comparator function, without preserving the order of equal values. This sort is in-place
+    /// and does not allocate.
+    fn sort_unstable_values_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sort this slice with a key extraction function, without preserving the order of equal values. This sort is
+    /// in-place and does not allocate.
+    fn sort_unstable_values_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Sort this slice, preserving the order of equal values. The values are moved through an aligned scratch buffer.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::{slice::UnalignedSliceExt, Unaligned};
+    /// let mut values = ["b", "c", "a"].map(|s| Unaligned::new(String::from(s)));
+    /// values.sort_values();
+    /// assert_eq!(vec!["a", "b", "c"], values.map(Unaligned::into_inner));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort_values(&mut self)
+    where
+        T: Ord;
+
+    /// Sort this slice with a comparator function, preserving the order of equal values. The values are moved through
+    /// an aligned scratch buffer.
+    #[cfg(feature = "alloc")]
+    fn sort_values_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering;
+
+    /// Sort this slice with a key extraction function, preserving the order of equal values. The values are moved
+    /// through an aligned scratch buffer.
+    #[cfg(feature = "alloc")]
+    fn sort_values_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+}
+
+impl<T> UnalignedSliceExt<T> for [Unaligned<T>] {
+    fn iter_copied(&self) -> IterCopied<'_, T>
+    where
+        T: Copy,
+    {
+        IterCopied(self.iter())
+    }
+
+    fn contains_value(&self, value: &T) -> bool
+    where
+        T: Copy + PartialEq,
+    {
+        self.iter().any(|v| v.get() == *value)
+    }
+
+    fn binary_search_value(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Copy + Ord,
+    {
+        self.binary_search_value_by(|v| v.cmp(value))
+    }
+
+    fn binary_search_value_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        T: Copy,
+        F: FnMut(&T) -> Ordering,
+    {
+        self.binary_search_by(|v| f(&v.get()))
+    }
+
+    fn fill_value(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for v in self {
+            v.set(value.clone());
+        }
+    }
+
+    fn swap_values(&mut self, a: usize, b: usize) {
+        self.swap(a, b);
+    }
+
+    fn copy_from_aligned(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        Unaligned::copy_from_aligned(self, src);
+    }
+
+    fn copy_to_aligned(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        Unaligned::copy_into_aligned(self, dst);
+    }
+
+    fn sort_unstable_values(&mut self)
+    where
+        T: Ord,
+    {
+        heapsort(self, &mut |a: &T, b: &T| a.lt(b));
+    }
+
+    fn sort_unstable_values_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        heapsort(self, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+    }
+
+    fn sort_unstable_values_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        heapsort(self, &mut |a: &T, b: &T| f(a).lt(&f(b)));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort_values(&mut self)
+    where
+        T: Ord,
+    {
+        with_scratch(self, |scratch| scratch.sort());
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort_values_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        with_scratch(self, |scratch| scratch.sort_by(compare));
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort_values_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        with_scratch(self, |scratch| scratch.sort_by_key(f));
+    }
+}
+
+/// Compare the values at two distinct indices by moving them into aligned storage.
+fn is_less_at<T, F>(v: &mut [Unaligned<T>], a: usize, b: usize, is_less: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    debug_assert_ne!(a, b);
+    let (left, right) = v.split_at_mut(usize::max(a, b));
+    let (low, high) = (&mut left[usize::min(a, b)], &mut right[0]);
+    let (x, y) = if a < b { (low, high) } else { (high, low) };
+    x.with_mut(|x| y.with_mut(|y| is_less(x, y)))
+}
+
+/// An in-place heapsort, which swaps elements bytewise and only moves them into aligned storage for comparisons.
+fn heapsort<T, F>(v: &mut [Unaligned<T>], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    fn sift_down<T, F>(v: &mut [Unaligned<T>], mut node: usize, is_less: &mut F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        loop {
+            let mut child = 2 * node + 1;
+            if child >= v.len() {
+                break;
+            }
+            if child + 1 < v.len() && is_less_at(v, child, child + 1, is_less) {
+                child += 1;
+            }
+            if !is_less_at(v, node, child, is_less) {
+                break;
+            }
+            v.swap(node, child);
+            node = child;
+        }
+    }
+
+    for node in (0..v.len() / 2).rev() {
+        sift_down(v, node, is_less);
+    }
+    for end in (1..v.len()).rev() {
+        v.swap(0, end);
+        sift_down(&mut v[..end], 0, is_less);
+    }
+}
+
+/// Move the values of a slice into an aligned scratch buffer, perform some operation on them, and move them back.
+#[cfg(feature = "alloc")]
+fn with_scratch<T, F>(v: &mut [Unaligned<T>], f: F)
+where
+    F: FnOnce(&mut [T]),
+{
+    // The values are written back to the slice after f exits, even under unwinding. The scratch buffer is allocated
+    // up front so that no allocation failure can occur while the values are only partially moved.
+    let mut guard = scopeguard::guard(
+        (Vec::with_capacity(v.len()), &mut *v),
+        |(mut scratch, v): (Vec<T>, &mut [Unaligned<T>])| {
+            for (dst, value) in v.iter_mut().zip(scratch.drain(..)) {
+                // SAFETY: The value at dst was moved into the scratch buffer, so overwriting it does not leak.
+                unsafe { dst.as_mut_ptr().write_unaligned(value) };
+            }
+        },
+    );
+    let (scratch, v) = &mut *guard;
+    for value in v.iter() {
+        // SAFETY: The value is moved into the scratch buffer, and is moved back when the guard is dropped. The
+        // scratch buffer has sufficient capacity, so this push does not reallocate or panic.
+        scratch.push(unsafe { value.as_ptr().read_unaligned() });
+    }
+    f(scratch);
+}
+
+/// An iterator over copies of the values in a slice of unaligned values.
+///
+/// This struct is created by [`UnalignedSliceExt::iter_copied`].
+#[derive(Debug, Clone)]
+pub struct IterCopied<'a, T>(slice::Iter<'a, Unaligned<T>>);
+
+impl<T: Copy> Iterator for IterCopied<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Unaligned::get)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(Unaligned::get)
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for IterCopied<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Unaligned::get)
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for IterCopied<'_, T> {}
+
+impl<T: Copy> FusedIterator for IterCopied<'_, T> {}
@@ -0,0 +1,143 @@
+//! Helpers for testing code with unaligned values at every possible misalignment.
+//!
+//! Whether an [`Unaligned<T>`] happens to be aligned depends on where it is placed, so code paths such as
+//! [`Unaligned::get_aligned`] can be difficult to exercise deterministically. The helpers in this module place
+//! values at chosen offsets from a base aligned to [`BASE_ALIGN`] bytes.
+
+use core::{
+    mem::{self, MaybeUninit},
+    ops::{Deref, DerefMut},
+};
+
+use crate::Unaligned;
+
+/// The alignment in bytes of the base from which values are offset. This is at least the alignment of any primitive
+/// type.
+pub const BASE_ALIGN: usize = 64;
+
+/// A zero-sized type that raises the alignment of any struct containing it.
+#[repr(align(64))]
+struct MaxAlign;
+
+/// An unaligned value placed exactly `OFFSET` bytes after an address aligned to [`BASE_ALIGN`] bytes. This type
+/// dereferences to [`Unaligned<T>`].
+///
+/// ## Example
+/// ```
+/// # use unaligned::testing::Misaligned;
+/// let mut aligned = Misaligned::<u32, 0>::new(1);
+/// let mut misaligned = Misaligned::<u32, 1>::new(2);
+/// assert!(aligned.get_aligned().is_some());
+/// assert!(misaligned.get_aligned().is_none());
+/// misaligned.with_mut(|v| *v += 1);
+/// assert_eq!(3, misaligned.into_inner());
+/// ```
+#[repr(C)]
+pub struct Misaligned<T, const OFFSET: usize> {
+    align: [MaxAlign; 0],
+    padding: [u8; OFFSET],
+    value: Unaligned<T>,
+}
+
+impl<T, const OFFSET: usize> Misaligned<T, OFFSET> {
+    /// Place the given value at the offset.
+    pub const fn new(value: T) -> Self {
+        Self {
+            align: [],
+            padding: [0; OFFSET],
+            value: Unaligned::new(value),
+        }
+    }
+
+    /// Consume this `Misaligned` and return the inner value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Get a shared reference to the unaligned value.
+    pub const fn as_unaligned(&self) -> &Unaligned<T> {
+        &self.value
+    }
+
+    /// Get a mutable reference to the unaligned value.
+    pub fn as_unaligned_mut(&mut self) -> &mut Unaligned<T> {
+        &mut self.value
+    }
+}
+
+impl<T: Default, const OFFSET: usize> Default for Misaligned<T, OFFSET> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, const OFFSET: usize> Deref for Misaligned<T, OFFSET> {
+    type Target = Unaligned<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, const OFFSET: usize> DerefMut for Misaligned<T, OFFSET> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// Storage in which a value of type `T` can be placed at any offset less than [`BASE_ALIGN`].
+#[repr(C)]
+struct Slots<T> {
+    align: [MaxAlign; 0],
+    head: [MaybeUninit<u8>; BASE_ALIGN],
+    tail: MaybeUninit<T>,
+}
+
+/// Run the given function with the value placed at every offset from `0` to `align_of::<T>() - 1` bytes after an
+/// aligned address, in order, and return the final value. The function therefore sees the value aligned exactly
+/// once, and misaligned by every possible amount.
+///
+/// If the function panics, the value is dropped.
+///
+/// ## Panics
+/// This function panics if the alignment of `T` is greater than [`BASE_ALIGN`].
+///
+/// ## Example
+/// ```
+/// # use unaligned::testing::for_each_misalignment;
+/// let mut aligned = 0;
+/// let value = for_each_misalignment(0u32, |v| {
+///     if v.get_aligned().is_some() {
+///         aligned += 1;
+///     }
+///     v.with_mut(|v| *v += 1);
+/// });
+/// assert_eq!(1, aligned);
+/// assert_eq!(4, value);
+/// ```
+pub fn for_each_misalignment<T, F>(value: T, mut f: F) -> T
+where
+    F: FnMut(&mut Unaligned<T>),
+{
+    assert!(
+        mem::align_of::<T>() <= BASE_ALIGN,
+        "alignment should be at most BASE_ALIGN"
+    );
+    let mut slots = MaybeUninit::<Slots<T>>::uninit();
+    let base = slots.as_mut_ptr().cast::<u8>();
+    let mut value = value;
+    for offset in 0..mem::align_of::<T>() {
+        // SAFETY: The offset is less than BASE_ALIGN, so the slot lies within the storage. Unaligned<T> has
+        // alignment 1, and the slot is not otherwise accessed while it holds the value.
+        unsafe {
+            let slot = base.add(offset).cast::<Unaligned<T>>();
+            slot.write(Unaligned::new(value));
+            // drop the value if the function panics
+            let guard = scopeguard::guard((), move |()| drop(slot.read()));
+            f(&mut *slot);
+            scopeguard::ScopeGuard::into_inner(guard);
+            value = slot.read().into_inner();
+        }
+    }
+    value
+}
@@ -0,0 +1,147 @@
+//! Packed tuples, which can be split into individually unaligned fields.
+//!
+//! [`Unaligned::into_array_of_unaligned`] splits an unaligned array into unaligned elements. The types in this
+//! module do the same for tuples of up to eight values. A packed tuple has `#[repr(C, packed)]` layout, so its fields
+//! are stored in order with no padding, and it can be viewed as a tuple of references to [`Unaligned`] fields. This
+//! allows disjoint mutable borrows of the fields of a single packed record.
+//!
+//! The [`packed_tuple!`](crate::packed_tuple) macro names the packed tuple type for a list of field types.
+//!
+//! ## Example
+//! ```
+//! # use unaligned::packed_tuple;
+//! let mut record: packed_tuple!(u8, u32, u16) = (1, 2, 3).into();
+//! let (tag, value, len) = record.as_unaligned_mut();
+//! value.with_mut(|v| *v += u32::from(tag.get()));
+//! len.set(4);
+//! assert_eq!(7, core::mem::size_of_val(&record));
+//! assert_eq!((1, 3, 4), record.into_inner());
+//! ```
+
+use core::{fmt::Debug, ptr};
+
+use crate::Unaligned;
+
+/// Name the packed tuple type with the given field types. Between one and eight field types are supported.
+///
+/// ## Example
+/// ```
+/// # use unaligned::{packed_tuple, tuple::PackedTuple2};
+/// let pair: packed_tuple!(u16, u64) = PackedTuple2::new(1, 2);
+/// assert_eq!(10, core::mem::size_of_val(&pair));
+/// ```
+#[macro_export]
+macro_rules! packed_tuple {
+    ($a:ty $(,)?) => {
+        $crate::tuple::PackedTuple1<$a>
+    };
+    ($a:ty, $b:ty $(,)?) => {
+        $crate::tuple::PackedTuple2<$a, $b>
+    };
+    ($a:ty, $b:ty, $c:ty $(,)?) => {
+        $crate::tuple::PackedTuple3<$a, $b, $c>
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty $(,)?) => {
+        $crate::tuple::PackedTuple4<$a, $b, $c, $d>
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty $(,)?) => {
+        $crate::tuple::PackedTuple5<$a, $b, $c, $d, $e>
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty $(,)?) => {
+        $crate::tuple::PackedTuple6<$a, $b, $c, $d, $e, $f>
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty $(,)?) => {
+        $crate::tuple::PackedTuple7<$a, $b, $c, $d, $e, $f, $g>
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty $(,)?) => {
+        $crate::tuple::PackedTuple8<$a, $b, $c, $d, $e, $f, $g, $h>
+    };
+}
+
+macro_rules! packed_tuples {
+    ($($name:ident($len:literal; $($ty:ident $var:ident $idx:tt),+)),* $(,)?) => {
+        $(
+            #[doc = concat!("A packed tuple of ", stringify!($len), " values. See the [module documentation](self) for more details.")]
+            #[repr(C, packed)]
+            #[derive(Default)]
+            pub struct $name<$($ty),+>($($ty),+);
+
+            impl<$($ty),+> $name<$($ty),+> {
+                /// Construct a new packed tuple with the given values.
+                #[allow(clippy::too_many_arguments)]
+                pub const fn new($($var: $ty),+) -> Self {
+                    Self($($var),+)
+                }
+
+                /// Consume this packed tuple and return its values as a tuple.
+                pub fn into_inner(self) -> ($($ty,)+) {
+                    let Self($($var),+) = self;
+                    ($($var,)+)
+                }
+
+                /// Consume this packed tuple and return its values as a tuple of unaligned values.
+                pub fn into_unaligned(self) -> ($(Unaligned<$ty>,)+) {
+                    let Self($($var),+) = self;
+                    ($(Unaligned::new($var),)+)
+                }
+
+                /// View the fields of this packed tuple as unaligned values.
+                pub fn as_unaligned(&self) -> ($(&Unaligned<$ty>,)+) {
+                    // SAFETY: Each field pointer is valid for reads, and Unaligned<T> has alignment 1.
+                    unsafe { ($(Unaligned::from_ptr(ptr::addr_of!(self.$idx)),)+) }
+                }
+
+                /// View the fields of this packed tuple as mutable unaligned values. The fields do not overlap, so
+                /// they can be borrowed mutably at the same time.
+                pub fn as_unaligned_mut(&mut self) -> ($(&mut Unaligned<$ty>,)+) {
+                    // SAFETY: Each field pointer is valid for reads and writes, the fields do not overlap, and
+                    // Unaligned<T> has alignment 1.
+                    unsafe { ($(Unaligned::from_mut_ptr(ptr::addr_of_mut!(self.$idx)),)+) }
+                }
+            }
+
+            impl<$($ty),+> From<($($ty,)+)> for $name<$($ty),+> {
+                fn from(($($var,)+): ($($ty,)+)) -> Self {
+                    Self($($var),+)
+                }
+            }
+
+            impl<$($ty),+> From<$name<$($ty),+>> for ($($ty,)+) {
+                fn from(value: $name<$($ty),+>) -> Self {
+                    value.into_inner()
+                }
+            }
+
+            impl<$($ty),+> From<$name<$($ty),+>> for ($(Unaligned<$ty>,)+) {
+                fn from(value: $name<$($ty),+>) -> Self {
+                    value.into_unaligned()
+                }
+            }
+
+            impl<$($ty: Copy),+> Clone for $name<$($ty),+> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<$($ty: Copy),+> Copy for $name<$($ty),+> {}
+
+            impl<$($ty),+> Debug for $name<$($ty),+> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&"<unaligned>").finish()
+                }
+            }
+        )*
+    };
+}
+
+packed_tuples!(
+    PackedTuple1(1; A a 0),
+    PackedTuple2(2; A a 0, B b 1),
+    PackedTuple3(3; A a 0, B b 1, C c 2),
+    PackedTuple4(4; A a 0, B b 1, C c 2, D d 3),
+    PackedTuple5(5; A a 0, B b 1, C c 2, D d 3, E e 4),
+    PackedTuple6(6; A a 0, B b 1, C c 2, D d 3, E e 4, F f 5),
+    PackedTuple7(7; A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6),
+    PackedTuple8(8; A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7),
+);
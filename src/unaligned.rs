@@ -1,9 +1,21 @@
 use core::{
+    array,
     fmt::Debug,
-    mem::{self, ManuallyDrop},
-    ptr,
+    iter::FusedIterator,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ptr, slice,
 };
 
+use crate::{
+    array_vec::PackedArrayVec,
+    pod::Pod,
+    ptr::UnalignedPtr,
+    slice::{IterCopied, UnalignedSliceExt},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// An unaligned value of type `T`. See the crate documentation for more details.
 #[repr(C, packed)]
 #[derive(Default)]
@@ -167,6 +179,98 @@ impl<T> Unaligned<T> {
             f(&mut *guard)
         }
     }
+
+    /// Like [`Unaligned::with_mut`], but the inner value is moved out and back with volatile accesses. See
+    /// [`UnalignedPtr::read_volatile`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// // ordinary memory standing in for a memory-mapped register block
+    /// let mut block = vec![0u8; 5];
+    /// let register = Unaligned::<u32>::from_bytes_mut(&mut block[1..]).unwrap();
+    /// register.write_volatile(0x0f);
+    /// register.with_mut_volatile(|bits| *bits |= 0xf0);
+    /// assert_eq!(0xff, register.read_volatile());
+    /// ```
+    pub fn with_mut_volatile<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let ptr = UnalignedPtr::from(self);
+        // SAFETY: The pointer comes from a mutable reference, so it is valid for reads and writes and no one else can
+        // access the value. The value is always written back after f exits, even under unwinding.
+        unsafe {
+            let mut guard = scopeguard::guard(ptr.read_volatile(), |v| ptr.write_volatile(v));
+            f(&mut *guard)
+        }
+    }
+
+    /// Mutably borrow the inner values of two `Unaligned`s at once and perform some computation with them. Both
+    /// values are written back after `f` exits, even under unwinding. See [`Unaligned::with_mut`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let mut a = Unaligned::new(1);
+    /// let mut b = Unaligned::new(2);
+    /// Unaligned::with_mut2(&mut a, &mut b, |x, y| {
+    ///     *x += *y;
+    ///     *y = 0;
+    /// });
+    /// assert_eq!((3, 0), (a.get(), b.get()));
+    /// ```
+    ///
+    /// If `f` panics, both values are still written back.
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// # use std::panic::{self, AssertUnwindSafe};
+    /// let mut a = Unaligned::new(String::from("a"));
+    /// let mut b = Unaligned::new(String::from("b"));
+    /// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     Unaligned::with_mut2(&mut a, &mut b, |x, y| {
+    ///         x.push('!');
+    ///         core::mem::swap(x, y);
+    ///         panic!("interrupted");
+    ///     })
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!("b", a.into_inner());
+    /// assert_eq!("a!", b.into_inner());
+    /// ```
+    pub fn with_mut2<R, F>(a: &mut Self, b: &mut Self, f: F) -> R
+    where
+        F: FnOnce(&mut T, &mut T) -> R,
+    {
+        a.with_mut(|a| b.with_mut(|b| f(a, b)))
+    }
+
+    /// View a slice of aligned values as a slice of unaligned values. This conversion is free.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let aligned = [1, 2, 3];
+    /// let unaligned: &[Unaligned<i32>] = Unaligned::slice_from_aligned(&aligned);
+    /// assert_eq!(2, unaligned[1].get());
+    /// ```
+    pub fn slice_from_aligned(slice: &[T]) -> &[Self] {
+        // SAFETY: T and Unaligned<T> have the same size and validity, and Unaligned<T> has a weaker alignment.
+        unsafe { &*(slice as *const [T] as *const [Self]) }
+    }
+
+    /// View a mutable slice of aligned values as a mutable slice of unaligned values. This conversion is free.
+    pub fn slice_from_aligned_mut(slice: &mut [T]) -> &mut [Self] {
+        // SAFETY: T and Unaligned<T> have the same size and validity, and Unaligned<T> has a weaker alignment.
+        // Any value written through the returned slice is a valid T, which may then be read aligned.
+        unsafe { &mut *(slice as *mut [T] as *mut [Self]) }
+    }
+
+    /// Get the number of values of type `T` that fit exactly into the given number of bytes.
+    fn slice_len_of(byte_len: usize) -> Option<usize> {
+        let size = mem::size_of::<T>();
+        (size != 0 && byte_len % size == 0).then(|| byte_len / size)
+    }
 }
 
 impl<T: Default> Unaligned<T> {
@@ -181,6 +285,432 @@ impl<T: Copy> Unaligned<T> {
     pub const fn get(&self) -> T {
         self.0
     }
+
+    /// Copy the inner value of this `Unaligned` with volatile accesses. See [`UnalignedPtr::read_volatile`] for
+    /// details.
+    pub fn read_volatile(&self) -> T {
+        // SAFETY: The pointer comes from a shared reference to a valid value, and T is Copy.
+        unsafe { UnalignedPtr::from(self).read_volatile() }
+    }
+
+    /// Set the inner value of this `Unaligned` with volatile accesses. See [`UnalignedPtr::read_volatile`] for
+    /// details.
+    pub fn write_volatile(&mut self, value: T) {
+        // SAFETY: The pointer comes from a mutable reference, and T is Copy, so the old value need not be dropped.
+        unsafe { UnalignedPtr::from(self).write_volatile(value) };
+    }
+
+    /// Copy all values from a slice of aligned values into a slice of unaligned values. This compiles down to
+    /// a single `memcpy`.
+    ///
+    /// ## Panics
+    /// This function panics if the two slices have different lengths.
+    pub fn copy_from_aligned(dst: &mut [Self], src: &[T]) {
+        dst.copy_from_slice(Self::slice_from_aligned(src));
+    }
+
+    /// Copy all values from a slice of unaligned values into a slice of aligned values. This compiles down to
+    /// a single `memcpy`.
+    ///
+    /// ## Panics
+    /// This function panics if the two slices have different lengths.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let unaligned = [1, 2, 3].map(Unaligned::new);
+    /// let mut aligned = [0; 3];
+    /// Unaligned::copy_into_aligned(&unaligned, &mut aligned);
+    /// assert_eq!([1, 2, 3], aligned);
+    /// ```
+    pub fn copy_into_aligned(src: &[Self], dst: &mut [T]) {
+        Self::slice_from_aligned_mut(dst).copy_from_slice(src);
+    }
+}
+
+impl<T: Pod> Unaligned<T> {
+    /// View this value as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: T is a Pod type, so every byte of the value is initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr().cast(), mem::size_of::<T>()) }
+    }
+
+    /// View this value as mutable bytes.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: T is a Pod type, so every byte of the value is initialized and any bytes written are a valid value.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr().cast(), mem::size_of::<T>()) }
+    }
+
+    /// View the given bytes as an unaligned value. If the length of `bytes` is not the size of `T`, this function
+    /// returns `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let bytes = [0, 1, 0, 0, 0];
+    /// let value = Unaligned::<u32>::from_bytes(&bytes[1..]).unwrap();
+    /// assert_eq!(1, u32::from_le(value.get()));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<&Self> {
+        // SAFETY: The length is checked, Unaligned<T> has alignment 1, and any bytes are a valid value of a Pod type.
+        (bytes.len() == mem::size_of::<T>()).then(|| unsafe { &*bytes.as_ptr().cast() })
+    }
+
+    /// View the given mutable bytes as an unaligned value. If the length of `bytes` is not the size of `T`, this
+    /// function returns `None`.
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Option<&mut Self> {
+        // SAFETY: The length is checked, Unaligned<T> has alignment 1, and any bytes are a valid value of a Pod type.
+        (bytes.len() == mem::size_of::<T>()).then(|| unsafe { &mut *bytes.as_mut_ptr().cast() })
+    }
+
+    /// View a slice of unaligned values as bytes.
+    pub fn slice_as_bytes(slice: &[Self]) -> &[u8] {
+        // SAFETY: T is a Pod type, so every byte of the slice is initialized.
+        unsafe { slice::from_raw_parts(slice.as_ptr().cast(), mem::size_of_val(slice)) }
+    }
+
+    /// View a mutable slice of unaligned values as mutable bytes.
+    pub fn slice_as_bytes_mut(slice: &mut [Self]) -> &mut [u8] {
+        // SAFETY: T is a Pod type, so every byte of the slice is initialized and any bytes written are valid values.
+        unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), mem::size_of_val(slice)) }
+    }
+
+    /// View the given bytes as a slice of unaligned values. If the length of `bytes` is not a multiple of the size
+    /// of `T`, or if `T` is zero-sized, this function returns `None`.
+    pub fn slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        let len = Self::slice_len_of(bytes.len())?;
+        // SAFETY: The length is checked, Unaligned<T> has alignment 1, and any bytes are a valid value of a Pod type.
+        Some(unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), len) })
+    }
+
+    /// View the given mutable bytes as a mutable slice of unaligned values. If the length of `bytes` is not a
+    /// multiple of the size of `T`, or if `T` is zero-sized, this function returns `None`.
+    pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> Option<&mut [Self]> {
+        let len = Self::slice_len_of(bytes.len())?;
+        // SAFETY: The length is checked, Unaligned<T> has alignment 1, and any bytes are a valid value of a Pod type.
+        Some(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), len) })
+    }
+
+    /// View this value as an unaligned value of another plain data type of the same size. If the sizes of `T` and
+    /// `U` differ, this method fails to compile.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let bytes = Unaligned::new([1u8, 0, 0, 0]);
+    /// let value = bytes.cast_ref::<u32>();
+    /// assert_eq!(1, u32::from_le(value.get()));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use unaligned::Unaligned;
+    /// let bytes = Unaligned::new([1u8, 0, 0]);
+    /// let value = bytes.cast_ref::<u32>();
+    /// ```
+    pub fn cast_ref<U: Pod>(&self) -> &Unaligned<U> {
+        const {
+            assert!(
+                mem::size_of::<T>() == mem::size_of::<U>(),
+                "types should have the same size"
+            )
+        };
+        // SAFETY: The sizes are equal, Unaligned<U> has alignment 1, and any bytes are a valid value of a Pod type.
+        unsafe { &*self.as_ptr().cast() }
+    }
+
+    /// View this value as a mutable unaligned value of another plain data type of the same size. If the sizes of
+    /// `T` and `U` differ, this method fails to compile.
+    pub fn cast_mut<U: Pod>(&mut self) -> &mut Unaligned<U> {
+        const {
+            assert!(
+                mem::size_of::<T>() == mem::size_of::<U>(),
+                "types should have the same size"
+            )
+        };
+        // SAFETY: The sizes are equal, Unaligned<U> has alignment 1, and any bytes written through either type are a
+        // valid value of the other, as both are Pod types.
+        unsafe { &mut *self.as_mut_ptr().cast() }
+    }
+
+    /// Convert this value into an unaligned value of another plain data type of the same size. If the sizes of `T`
+    /// and `U` differ, this method fails to compile.
+    pub fn into_cast<U: Pod>(self) -> Unaligned<U> {
+        *self.cast_ref()
+    }
+}
+
+impl<T> Unaligned<MaybeUninit<T>> {
+    /// Construct a new `Unaligned` with uninitialized contents.
+    pub const fn uninit() -> Self {
+        Self(MaybeUninit::uninit())
+    }
+
+    /// Construct a new `Unaligned` with contents filled with zero bytes. Depending on `T`, this may or may not be a
+    /// valid value.
+    pub const fn zeroed() -> Self {
+        Self(MaybeUninit::zeroed())
+    }
+
+    /// Construct an array of `Unaligned` values with uninitialized contents.
+    pub const fn uninit_array<const N: usize>() -> [Self; N] {
+        [const { Self::uninit() }; N]
+    }
+
+    /// Initialize the contents of this `Unaligned` with the given value, and return a mutable reference to it. Any
+    /// previous contents are overwritten without being dropped.
+    ///
+    /// ## Example
+    /// ```
+    /// # use core::mem::MaybeUninit;
+    /// # use unaligned::Unaligned;
+    /// let mut slot = Unaligned::<MaybeUninit<u32>>::uninit();
+    /// let value = slot.write(7);
+    /// value.with_mut(|v| *v += 1);
+    /// assert_eq!(8, unsafe { slot.assume_init() }.get());
+    /// ```
+    pub fn write(&mut self, value: T) -> &mut Unaligned<T> {
+        self.0 = MaybeUninit::new(value);
+        // SAFETY: The contents were just initialized, and MaybeUninit<T> has the same layout as T.
+        unsafe { self.assume_init_mut() }
+    }
+
+    /// Extract the value from this `Unaligned`.
+    ///
+    /// ## Safety
+    /// The contents must be initialized to a valid value of type `T`. See [`MaybeUninit::assume_init`] for details.
+    pub unsafe fn assume_init(self) -> Unaligned<T> {
+        // SAFETY: The caller has ensured that the contents are initialized.
+        Unaligned(unsafe { self.into_inner().assume_init() })
+    }
+
+    /// Get a shared reference to the initialized contents of this `Unaligned`.
+    ///
+    /// ## Safety
+    /// The contents must be initialized to a valid value of type `T`.
+    pub unsafe fn assume_init_ref(&self) -> &Unaligned<T> {
+        // SAFETY: The caller has ensured that the contents are initialized, and MaybeUninit<T> has the same layout
+        // as T.
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    /// Get a mutable reference to the initialized contents of this `Unaligned`.
+    ///
+    /// ## Safety
+    /// The contents must be initialized to a valid value of type `T`.
+    pub unsafe fn assume_init_mut(&mut self) -> &mut Unaligned<T> {
+        // SAFETY: The caller has ensured that the contents are initialized, and MaybeUninit<T> has the same layout
+        // as T.
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+
+    /// View a slice of `Unaligned` values as a slice of their initialized contents.
+    ///
+    /// ## Safety
+    /// The contents of every value in the slice must be initialized to a valid value of type `T`.
+    pub unsafe fn slice_assume_init_ref(slice: &[Self]) -> &[Unaligned<T>] {
+        // SAFETY: The caller has ensured that the contents are initialized, and MaybeUninit<T> has the same layout
+        // as T.
+        unsafe { &*(slice as *const [Self] as *const [Unaligned<T>]) }
+    }
+
+    /// View a mutable slice of `Unaligned` values as a mutable slice of their initialized contents.
+    ///
+    /// ## Safety
+    /// The contents of every value in the slice must be initialized to a valid value of type `T`.
+    pub unsafe fn slice_assume_init_mut(slice: &mut [Self]) -> &mut [Unaligned<T>] {
+        // SAFETY: The caller has ensured that the contents are initialized, and MaybeUninit<T> has the same layout
+        // as T.
+        unsafe { &mut *(slice as *mut [Self] as *mut [Unaligned<T>]) }
+    }
+
+    /// View this `Unaligned` as possibly uninitialized bytes.
+    pub fn as_uninit_bytes(&self) -> &[MaybeUninit<u8>] {
+        // SAFETY: MaybeUninit<u8> may hold any byte, including an uninitialized one, and has alignment 1.
+        unsafe { slice::from_raw_parts(self.as_ptr().cast(), mem::size_of::<T>()) }
+    }
+
+    /// View this `Unaligned` as mutable, possibly uninitialized bytes.
+    pub fn as_uninit_bytes_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        // SAFETY: MaybeUninit<u8> may hold any byte, including an uninitialized one, and has alignment 1. Any bytes
+        // written are valid contents of a MaybeUninit<T>.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr().cast(), mem::size_of::<T>()) }
+    }
+
+    /// View the given mutable, possibly uninitialized bytes as an `Unaligned` slot for a value of type `T`. If the
+    /// length of `bytes` is not the size of `T`, this function returns `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use core::mem::MaybeUninit;
+    /// # use unaligned::Unaligned;
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 6];
+    /// let (tag, len) = buf.split_at_mut(2);
+    /// Unaligned::<MaybeUninit<u16>>::from_uninit_bytes_mut(tag).unwrap().write(1);
+    /// Unaligned::<MaybeUninit<u32>>::from_uninit_bytes_mut(len).unwrap().write(2);
+    /// // SAFETY: every byte of the buffer has been written
+    /// let bytes = buf.map(|b| unsafe { b.assume_init() });
+    /// assert_eq!(1, u16::from_ne_bytes([bytes[0], bytes[1]]));
+    /// ```
+    pub fn from_uninit_bytes_mut(bytes: &mut [MaybeUninit<u8>]) -> Option<&mut Self> {
+        // SAFETY: The length is checked, Unaligned<MaybeUninit<T>> has alignment 1, and any bytes are valid contents
+        // of a MaybeUninit<T>.
+        (bytes.len() == mem::size_of::<T>()).then(|| unsafe { &mut *bytes.as_mut_ptr().cast() })
+    }
+
+    /// View the given mutable, possibly uninitialized bytes as a slice of `Unaligned` slots for values of type `T`.
+    /// If the length of `bytes` is not a multiple of the size of `T`, or if `T` is zero-sized, this function returns
+    /// `None`.
+    pub fn slice_from_uninit_bytes_mut(bytes: &mut [MaybeUninit<u8>]) -> Option<&mut [Self]> {
+        let len = Unaligned::<T>::slice_len_of(bytes.len())?;
+        // SAFETY: The length is checked, Unaligned<MaybeUninit<T>> has alignment 1, and any bytes are valid contents
+        // of a MaybeUninit<T>.
+        Some(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), len) })
+    }
+}
+
+impl<T: Copy> Unaligned<MaybeUninit<T>> {
+    /// Initialize a slice of `Unaligned` values by copying from a slice of aligned values, and return the
+    /// initialized slice.
+    ///
+    /// ## Panics
+    /// This function panics if the two slices have different lengths.
+    pub fn write_slice<'a>(dst: &'a mut [Self], src: &[T]) -> &'a mut [Unaligned<T>] {
+        assert_eq!(dst.len(), src.len(), "slices should have the same length");
+        for (dst, &src) in dst.iter_mut().zip(src) {
+            dst.write(src);
+        }
+        // SAFETY: Every value in the slice was just initialized.
+        unsafe { Self::slice_assume_init_mut(dst) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Unaligned<T> {
+    /// Mutably borrow the inner values of any number of `Unaligned`s at once and perform some computation with them.
+    /// The values are moved into an aligned scratch buffer, and every value is written back after `f` exits, even
+    /// under unwinding. See [`Unaligned::with_mut`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let mut a = Unaligned::new(3);
+    /// let mut b = Unaligned::new(1);
+    /// let mut c = Unaligned::new(2);
+    /// Unaligned::with_mut_all(&mut [&mut a, &mut b, &mut c], |values| values.sort());
+    /// assert_eq!([1, 2, 3], [a.get(), b.get(), c.get()]);
+    /// ```
+    ///
+    /// If `f` panics, every value is still written back.
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// # use std::panic::{self, AssertUnwindSafe};
+    /// let mut a = Unaligned::new(String::from("c"));
+    /// let mut b = Unaligned::new(String::from("a"));
+    /// let mut c = Unaligned::new(String::from("b"));
+    /// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     Unaligned::with_mut_all(&mut [&mut a, &mut b, &mut c], |values| {
+    ///         values.sort();
+    ///         values[2].push('!');
+    ///         panic!("interrupted");
+    ///     })
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!("a", a.into_inner());
+    /// assert_eq!("b", b.into_inner());
+    /// assert_eq!("c!", c.into_inner());
+    /// ```
+    pub fn with_mut_all<R, F>(values: &mut [&mut Self], f: F) -> R
+    where
+        F: FnOnce(&mut [T]) -> R,
+    {
+        let mut scratch = Vec::with_capacity(values.len());
+        for value in values.iter() {
+            // SAFETY: The value is only read with an unaligned operation. It is always written back below, and
+            // taking the values by mutable reference ensures that no one else can observe the duplicate.
+            scratch.push(unsafe { value.as_ptr().read_unaligned() });
+        }
+        // f only receives a slice, so the scratch buffer keeps exactly one value for each location
+        let mut guard = scopeguard::guard(scratch, |scratch| {
+            for (value, v) in values.iter_mut().zip(scratch) {
+                // SAFETY: The pointer is valid for unaligned writes, and the value read from it is written back
+                // exactly once.
+                unsafe { value.as_mut_ptr().write_unaligned(v) };
+            }
+        });
+        f(&mut guard)
+    }
+
+    /// Convert a vector of aligned values into a vector of unaligned values. If `T` has an alignment of 1, the
+    /// allocation is reused. Otherwise, the values are moved into a new allocation with a single `memcpy`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let unaligned = Unaligned::vec_from_aligned(vec![String::from("Hello"), String::from("world")]);
+    /// assert_eq!(2, unaligned.len());
+    /// ```
+    pub fn vec_from_aligned(vec: Vec<T>) -> Vec<Self> {
+        let mut vec = ManuallyDrop::new(vec);
+        if mem::align_of::<T>() == 1 {
+            // SAFETY: T and Unaligned<T> have the same size and alignment, so the allocation has the same layout
+            // for both types. The ManuallyDrop ensures that the original vector does not free the allocation.
+            unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+        } else {
+            // if allocating panics, the original vector should still drop its values
+            let mut vec = ManuallyDrop::into_inner(vec);
+            let len = vec.len();
+            let mut out = Vec::<Self>::with_capacity(len);
+            // SAFETY: The new vector has the capacity for all values, and the values are moved bytewise, so the
+            // alignment of the destination does not matter. The original vector is emptied so that it only frees
+            // its allocation and does not drop the moved values.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    vec.as_ptr().cast::<u8>(),
+                    out.as_mut_ptr().cast::<u8>(),
+                    mem::size_of::<T>() * len,
+                );
+                vec.set_len(0);
+                out.set_len(len);
+            }
+            out
+        }
+    }
+
+    /// Convert a vector of unaligned values into a vector of aligned values. If `T` has an alignment of 1, the
+    /// allocation is reused. Otherwise, the values are moved into a new allocation with a single `memcpy`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let unaligned = vec![Unaligned::new(1u64), Unaligned::new(2)];
+    /// assert_eq!(vec![1, 2], Unaligned::vec_into_aligned(unaligned));
+    /// ```
+    pub fn vec_into_aligned(vec: Vec<Self>) -> Vec<T> {
+        let mut vec = ManuallyDrop::new(vec);
+        if mem::align_of::<T>() == 1 {
+            // SAFETY: T and Unaligned<T> have the same size and alignment, so the allocation has the same layout
+            // for both types. The ManuallyDrop ensures that the original vector does not free the allocation.
+            unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+        } else {
+            // if allocating panics, the original vector should still drop its values
+            let mut vec = ManuallyDrop::into_inner(vec);
+            let len = vec.len();
+            let mut out = Vec::<T>::with_capacity(len);
+            // SAFETY: The new vector has the capacity for all values, and the values are moved bytewise, so the
+            // alignment of the source does not matter. The original vector is emptied so that it only frees
+            // its allocation and does not drop the moved values.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    vec.as_ptr().cast::<u8>(),
+                    out.as_mut_ptr().cast::<u8>(),
+                    mem::size_of::<T>() * len,
+                );
+                vec.set_len(0);
+                out.set_len(len);
+            }
+            out
+        }
+    }
 }
 
 impl<T, const N: usize> Unaligned<[T; N]> {
@@ -204,10 +734,225 @@ impl<T, const N: usize> Unaligned<[T; N]> {
         // SAFETY: Unaligned<[T; N]> and [Unaligned<T>; N] have the same size, alignment, and validity.
         unsafe { mem::transmute(self) }
     }
+
+    /// Split this array into two arrays of length `K` and `M`, where `K + M == N`.
+    ///
+    /// ## Panics
+    /// This method panics if `K + M != N`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let values = Unaligned::new([1, 2, 3, 4, 5]);
+    /// let (head, tail): (_, &Unaligned<[i32; 3]>) = values.split_array_ref::<2, 3>();
+    /// assert_eq!([1, 2], head.get());
+    /// assert_eq!([3, 4, 5], tail.get());
+    /// ```
+    pub fn split_array_ref<const K: usize, const M: usize>(
+        &self,
+    ) -> (&Unaligned<[T; K]>, &Unaligned<[T; M]>) {
+        assert_eq!(N, K + M, "split lengths should add up to the array length");
+        // SAFETY: Both halves are in bounds and do not overlap.
+        unsafe { (self.sub_array(0), self.sub_array(K)) }
+    }
+
+    /// Split this array into two mutable arrays of length `K` and `M`, where `K + M == N`.
+    ///
+    /// ## Panics
+    /// This method panics if `K + M != N`.
+    pub fn split_array_mut<const K: usize, const M: usize>(
+        &mut self,
+    ) -> (&mut Unaligned<[T; K]>, &mut Unaligned<[T; M]>) {
+        assert_eq!(N, K + M, "split lengths should add up to the array length");
+        let (head, tail) = self.as_mut_array_of_unaligned().split_at_mut(K);
+        // SAFETY: The halves have lengths K and M, and Unaligned<[T; K]> has the same layout as K contiguous values
+        // of Unaligned<T>.
+        unsafe { (&mut *head.as_mut_ptr().cast(), &mut *tail.as_mut_ptr().cast()) }
+    }
+
+    /// Get the first `K` values of this array as an array, or return `None` if `K > N`.
+    pub fn first_chunk<const K: usize>(&self) -> Option<&Unaligned<[T; K]>> {
+        // SAFETY: The chunk is in bounds.
+        (K <= N).then(|| unsafe { self.sub_array(0) })
+    }
+
+    /// Get the first `K` values of this array as a mutable array, or return `None` if `K > N`.
+    pub fn first_chunk_mut<const K: usize>(&mut self) -> Option<&mut Unaligned<[T; K]>> {
+        // SAFETY: The chunk is in bounds.
+        (K <= N).then(|| unsafe { self.sub_array_mut(0) })
+    }
+
+    /// Get the last `K` values of this array as an array, or return `None` if `K > N`.
+    pub fn last_chunk<const K: usize>(&self) -> Option<&Unaligned<[T; K]>> {
+        // SAFETY: The chunk is in bounds.
+        (K <= N).then(|| unsafe { self.sub_array(N - K) })
+    }
+
+    /// Get the last `K` values of this array as a mutable array, or return `None` if `K > N`.
+    pub fn last_chunk_mut<const K: usize>(&mut self) -> Option<&mut Unaligned<[T; K]>> {
+        // SAFETY: The chunk is in bounds.
+        (K <= N).then(|| unsafe { self.sub_array_mut(N - K) })
+    }
+
+    /// Split this array into a slice of `M`-element arrays, starting at the beginning of the array, and a remainder
+    /// slice with length strictly less than `M`.
+    ///
+    /// ## Panics
+    /// This method panics if `M` is zero.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let values = Unaligned::new([1, 2, 3, 4, 5]);
+    /// let (chunks, remainder) = values.as_chunks::<2>();
+    /// assert_eq!([1, 2], chunks[0].get());
+    /// assert_eq!([3, 4], chunks[1].get());
+    /// assert_eq!(5, remainder[0].get());
+    /// ```
+    pub fn as_chunks<const M: usize>(&self) -> (&[Unaligned<[T; M]>], &[Unaligned<T>]) {
+        assert!(M != 0, "chunk size should be nonzero");
+        let (chunks, remainder) = self.as_array_of_unaligned().split_at(N / M * M);
+        // SAFETY: The chunks slice contains exactly N / M * M values, and has the same layout as N / M arrays of
+        // length M because Unaligned<T> has alignment 1.
+        let chunks = unsafe { slice::from_raw_parts(chunks.as_ptr().cast(), N / M) };
+        (chunks, remainder)
+    }
+
+    /// Split this array into a mutable slice of `M`-element arrays, starting at the beginning of the array, and a
+    /// remainder slice with length strictly less than `M`.
+    ///
+    /// ## Panics
+    /// This method panics if `M` is zero.
+    pub fn as_chunks_mut<const M: usize>(&mut self) -> (&mut [Unaligned<[T; M]>], &mut [Unaligned<T>]) {
+        assert!(M != 0, "chunk size should be nonzero");
+        let (chunks, remainder) = self.as_mut_array_of_unaligned().split_at_mut(N / M * M);
+        // SAFETY: The chunks slice contains exactly N / M * M values, and has the same layout as N / M arrays of
+        // length M because Unaligned<T> has alignment 1.
+        let chunks = unsafe { slice::from_raw_parts_mut(chunks.as_mut_ptr().cast(), N / M) };
+        (chunks, remainder)
+    }
+
+    /// View `K` values of this array, starting at the given index, as an array.
+    ///
+    /// ## Safety
+    /// The caller must ensure that `start + K <= N`.
+    unsafe fn sub_array<const K: usize>(&self, start: usize) -> &Unaligned<[T; K]> {
+        // SAFETY: The caller has ensured that the sub-array is in bounds. Unaligned<[T; N]> is laid out as N
+        // contiguous values of T, and Unaligned<[T; K]> has alignment 1, so any in-bounds offset is valid.
+        unsafe { &*self.as_array_of_unaligned().as_ptr().add(start).cast() }
+    }
+
+    /// View `K` values of this array, starting at the given index, as a mutable array.
+    ///
+    /// ## Safety
+    /// The caller must ensure that `start + K <= N`.
+    unsafe fn sub_array_mut<const K: usize>(&mut self, start: usize) -> &mut Unaligned<[T; K]> {
+        // SAFETY: The caller has ensured that the sub-array is in bounds. Unaligned<[T; N]> is laid out as N
+        // contiguous values of T, and Unaligned<[T; K]> has alignment 1, so any in-bounds offset is valid.
+        unsafe { &mut *self.as_mut_array_of_unaligned().as_mut_ptr().add(start).cast() }
+    }
+
+    /// Apply a function to each value of this array, returning an array of the results.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let values = Unaligned::new([1, 2, 3]);
+    /// assert_eq!([2, 4, 6], values.map(|v| v * 2).into_inner());
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Unaligned<[U; N]>
+    where
+        F: FnMut(T) -> U,
+    {
+        Unaligned::new(self.into_inner().map(f))
+    }
+
+    /// Combine this array with another array into an array of pairs.
+    pub fn zip<U>(self, other: Unaligned<[U; N]>) -> Unaligned<[(T, U); N]> {
+        let mut other = other.into_iter();
+        self.map(|value| (value, other.next().expect("arrays should have the same length")))
+    }
+
+    /// Mutably borrow each value of this array in turn, and perform some computation with it.
+    /// See [`Unaligned::with_mut`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let mut values = Unaligned::new([String::from("a"), String::from("b")]);
+    /// values.each_mut_with(|s| s.push('!'));
+    /// assert_eq!(["a!", "b!"], values.into_inner());
+    /// ```
+    pub fn each_mut_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for value in self.as_mut_array_of_unaligned() {
+            value.with_mut(&mut f);
+        }
+    }
+
+    /// Collect exactly `N` values from an iterator into an unaligned array. If the iterator yields fewer or more than
+    /// `N` values, this function returns `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::Unaligned;
+    /// let values = Unaligned::<[u8; 3]>::try_from_iter(1..=3).unwrap();
+    /// assert_eq!([1, 2, 3], values.get());
+    /// assert!(Unaligned::<[u8; 3]>::try_from_iter(1..=4).is_none());
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut values = PackedArrayVec::<T, N>::new();
+        for value in iter {
+            values.push(value).ok()?;
+        }
+        values.into_array().ok()
+    }
 }
 
 // trait implementations
 
+impl<T, const N: usize> IntoIterator for Unaligned<[T; N]> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.into_array_of_unaligned().into_iter())
+    }
+}
+
+impl<'a, T: Copy, const N: usize> IntoIterator for &'a Unaligned<[T; N]> {
+    type Item = T;
+    type IntoIter = IterCopied<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_array_of_unaligned().iter_copied()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut Unaligned<[T; N]> {
+    type Item = &'a mut Unaligned<T>;
+    type IntoIter = slice::IterMut<'a, Unaligned<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_array_of_unaligned().iter_mut()
+    }
+}
+
+/// Collects exactly `N` values into an unaligned array. See [`Unaligned::try_from_iter`] for a non-panicking version.
+///
+/// ## Panics
+/// Collecting panics if the iterator yields fewer or more than `N` values.
+impl<T, const N: usize> FromIterator<T> for Unaligned<[T; N]> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::try_from_iter(iter).expect("iterator should yield exactly N values")
+    }
+}
+
 impl<T> From<T> for Unaligned<T> {
     fn from(value: T) -> Self {
         Self::new(value)
@@ -228,3 +973,31 @@ impl<T> Debug for Unaligned<T> {
         f.debug_tuple("Unaligned").field(&"<unaligned>").finish()
     }
 }
+
+/// An iterator that moves values out of an unaligned array.
+///
+/// This struct is created by the `into_iter` method on [`Unaligned<[T; N]>`](Unaligned).
+#[derive(Debug)]
+pub struct IntoIter<T, const N: usize>(array::IntoIter<Unaligned<T>, N>);
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Unaligned::into_inner)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Unaligned::into_inner)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
@@ -0,0 +1,392 @@
+//! A growable vector of packed, unaligned values.
+
+use alloc::vec::{self, Vec};
+use core::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    mem,
+    ops::{Deref, DerefMut, RangeBounds},
+    slice,
+};
+
+use crate::{pod::Pod, Unaligned};
+
+/// A growable vector that stores values of type `T` packed, with no padding between them.
+///
+/// The elements are stored contiguously in a byte buffer with a stride of `size_of::<T>()`, so the contents of the
+/// vector can be viewed as bytes (see [`UnalignedVec::as_bytes`]) if `T` is a [`Pod`] type. This vector dereferences
+/// to a slice of [`Unaligned<T>`], so the methods of [`UnalignedSliceExt`](crate::slice::UnalignedSliceExt) may also
+/// be used.
+///
+/// ## Example
+/// ```
+/// # use unaligned::vec::UnalignedVec;
+/// let mut vec = UnalignedVec::new();
+/// vec.push(1u32);
+/// vec.push(2);
+/// vec.with_mut_at(0, |v| *v += 10);
+/// assert_eq!(Some(11), vec.get(0));
+/// assert_eq!(8, vec.as_bytes().len());
+/// ```
+///
+/// Values are dropped exactly once, whether they are removed by [`retain`](UnalignedVec::retain), removed by
+/// [`drain`](UnalignedVec::drain), or left in the vector when it is dropped.
+/// ```
+/// # use unaligned::vec::UnalignedVec;
+/// # use std::cell::RefCell;
+/// struct Noisy<'a>(&'a RefCell<Vec<u32>>, u32);
+///
+/// impl Drop for Noisy<'_> {
+///     fn drop(&mut self) {
+///         self.0.borrow_mut().push(self.1);
+///     }
+/// }
+///
+/// let log = RefCell::new(Vec::new());
+/// let mut vec: UnalignedVec<_> = (1..=6).map(|i| Noisy(&log, i)).collect();
+/// vec.retain(|v| v.1 % 2 == 0);
+/// assert_eq!(vec![1, 3, 5], *log.borrow());
+/// vec.drain(..);
+/// assert_eq!(vec![1, 3, 5, 2, 4, 6], *log.borrow());
+/// vec.push(Noisy(&log, 7));
+/// drop(vec);
+/// assert_eq!(vec![1, 3, 5, 2, 4, 6, 7], *log.borrow());
+/// ```
+pub struct UnalignedVec<T> {
+    buf: Vec<Unaligned<T>>,
+}
+
+impl<T> UnalignedVec<T> {
+    /// Construct a new, empty vector.
+    pub const fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+
+    /// Construct a new, empty vector with capacity for at least the given number of values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Get the number of values in this vector.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Return `true` if this vector contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Get the number of values this vector can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Reserve capacity for at least `additional` more values.
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(additional);
+    }
+
+    /// Append a value to the end of this vector.
+    pub fn push(&mut self, value: T) {
+        self.buf.push(Unaligned::new(value));
+    }
+
+    /// Remove the last value from this vector and return it, or return `None` if this vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.buf.pop().map(Unaligned::into_inner)
+    }
+
+    /// Insert a value at the given index, shifting all values after it to the right.
+    ///
+    /// ## Panics
+    /// This method panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.buf.insert(index, Unaligned::new(value));
+    }
+
+    /// Remove the value at the given index and return it, shifting all values after it to the left.
+    ///
+    /// ## Panics
+    /// This method panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.buf.remove(index).into_inner()
+    }
+
+    /// Set the value at the given index. The previous value is dropped.
+    ///
+    /// ## Panics
+    /// This method panics if the index is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) {
+        self.buf[index].set(value);
+    }
+
+    /// Mutably borrow the value at the given index and perform some computation with it. If the index is out of
+    /// bounds, this method returns `None`. See [`Unaligned::with_mut`] for details.
+    pub fn with_mut_at<R, F>(&mut self, index: usize, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.buf.get_mut(index).map(|v| v.with_mut(f))
+    }
+
+    /// Shorten this vector to the given length, dropping the remaining values.
+    pub fn truncate(&mut self, len: usize) {
+        self.buf.truncate(len);
+    }
+
+    /// Remove all values from this vector.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    /// Retain only the values for which the given predicate returns `true`. The order of the retained values is
+    /// preserved.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::vec::UnalignedVec;
+    /// let mut vec: UnalignedVec<_> = (1..=6).collect();
+    /// vec.retain(|v| v % 2 == 0);
+    /// assert_eq!(vec![2, 4, 6], vec.into_vec());
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.buf.retain_mut(|v| v.with_mut(|v| f(v)));
+    }
+
+    /// Remove the values in the given range from this vector, returning them in an iterator. Any values not consumed
+    /// by the iterator are dropped.
+    ///
+    /// ## Panics
+    /// This method panics if the range is out of bounds.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        Drain(self.buf.drain(range))
+    }
+
+    /// Iterate over the unaligned values in this vector.
+    pub fn iter(&self) -> slice::Iter<'_, Unaligned<T>> {
+        self.buf.iter()
+    }
+
+    /// Mutably iterate over the unaligned values in this vector.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Unaligned<T>> {
+        self.buf.iter_mut()
+    }
+
+    /// View the contents of this vector as a slice of unaligned values.
+    pub fn as_slice(&self) -> &[Unaligned<T>] {
+        &self.buf
+    }
+
+    /// View the contents of this vector as a mutable slice of unaligned values.
+    pub fn as_mut_slice(&mut self) -> &mut [Unaligned<T>] {
+        &mut self.buf
+    }
+
+    /// Convert this vector into a vector of aligned values. See [`Unaligned::vec_into_aligned`] for details.
+    pub fn into_vec(self) -> Vec<T> {
+        Unaligned::vec_into_aligned(self.buf)
+    }
+}
+
+impl<T: Copy> UnalignedVec<T> {
+    /// Copy the value at the given index, or return `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.buf.get(index).map(Unaligned::get)
+    }
+}
+
+impl<T: Pod> UnalignedVec<T> {
+    /// View the contents of this vector as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The values are stored contiguously, and T is a Pod type, so every byte is initialized.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().cast(), mem::size_of_val(&*self.buf)) }
+    }
+
+    /// Construct a vector by copying values from the given bytes. If the length of `bytes` is not a multiple of the
+    /// size of `T`, this function returns `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use unaligned::vec::UnalignedVec;
+    /// let vec = UnalignedVec::<u16>::from_bytes(&[1, 0, 2, 0, 3, 0]).unwrap();
+    /// assert_eq!(3, vec.len());
+    /// assert_eq!(vec.as_bytes(), [1, 0, 2, 0, 3, 0]);
+    /// assert!(UnalignedVec::<u16>::from_bytes(&[1, 0, 2]).is_none());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return bytes.is_empty().then(Self::new);
+        }
+        if bytes.len() % size != 0 {
+            return None;
+        }
+        let len = bytes.len() / size;
+        let mut buf = Vec::<Unaligned<T>>::with_capacity(len);
+        // SAFETY: The vector has capacity for len values, which is exactly bytes.len() bytes. T is a Pod type, so
+        // any bytes are a valid value.
+        unsafe {
+            buf.as_mut_ptr()
+                .cast::<u8>()
+                .copy_from_nonoverlapping(bytes.as_ptr(), bytes.len());
+            buf.set_len(len);
+        }
+        Some(Self { buf })
+    }
+}
+
+// trait implementations
+
+impl<T> Deref for UnalignedVec<T> {
+    type Target = [Unaligned<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.buf
+    }
+}
+
+impl<T> DerefMut for UnalignedVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buf
+    }
+}
+
+impl<T> Default for UnalignedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy> Clone for UnalignedVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+        }
+    }
+}
+
+impl<T: Copy + Debug> Debug for UnalignedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.buf.iter().map(Unaligned::get)).finish()
+    }
+}
+
+impl<T> From<Vec<T>> for UnalignedVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self {
+            buf: Unaligned::vec_from_aligned(vec),
+        }
+    }
+}
+
+impl<T> From<UnalignedVec<T>> for Vec<T> {
+    fn from(vec: UnalignedVec<T>) -> Self {
+        vec.into_vec()
+    }
+}
+
+impl<T> FromIterator<T> for UnalignedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            buf: iter.into_iter().map(Unaligned::new).collect(),
+        }
+    }
+}
+
+impl<T> Extend<T> for UnalignedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.buf.extend(iter.into_iter().map(Unaligned::new));
+    }
+}
+
+impl<T> IntoIterator for UnalignedVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.buf.into_iter())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UnalignedVec<T> {
+    type Item = &'a Unaligned<T>;
+    type IntoIter = slice::Iter<'a, Unaligned<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut UnalignedVec<T> {
+    type Item = &'a mut Unaligned<T>;
+    type IntoIter = slice::IterMut<'a, Unaligned<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator that moves values out of an [`UnalignedVec`].
+///
+/// This struct is created by the `into_iter` method on [`UnalignedVec`].
+#[derive(Debug)]
+pub struct IntoIter<T>(vec::IntoIter<Unaligned<T>>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Unaligned::into_inner)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Unaligned::into_inner)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// A draining iterator over values removed from an [`UnalignedVec`].
+///
+/// This struct is created by [`UnalignedVec::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T>(vec::Drain<'a, Unaligned<T>>);
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Unaligned::into_inner)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Unaligned::into_inner)
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}